@@ -285,6 +285,8 @@ pub fn create_sub_account(
         namespace,
         install_modules,
         account_id: account_id.map(AccountId::local),
+        proxy_admin: None,
+        linked_contracts: None,
     };
 
     let account_factory_addr = query_module(
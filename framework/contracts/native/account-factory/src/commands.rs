@@ -1,4 +1,5 @@
 use abstract_sdk::{
+    cw_helpers::Clearable,
     feature_objects::VersionControlContract,
     std::{
         manager::InstantiateMsg as ManagerInstantiateMsg,
@@ -9,24 +10,30 @@ use abstract_sdk::{
         },
         proxy::InstantiateMsg as ProxyInstantiateMsg,
         version_control::{
-            AccountBase, ExecuteMsg as VCExecuteMsg, ModulesResponse, QueryMsg as VCQuery,
+            AccountBase, ExecuteMsg as VCExecuteMsg, ModuleResponse, ModulesResponse,
+            NamespaceResponse, QueryMsg as VCQuery,
         },
         AbstractResult, MANAGER, PROXY,
     },
 };
 use abstract_std::{
+    account_factory::{BootstrapGovernance, ConfigChangeSubscriberMsg, ManagerOrGovernance},
     manager::ModuleInstallConfig,
     module_factory::SimulateInstallModulesResponse,
     objects::{
-        account::AccountTrace, module::assert_module_data_validity,
-        salt::generate_instantiate_salt, AccountId, AssetEntry, ABSTRACT_ACCOUNT_ID,
+        account::{AccountSequence, AccountTrace},
+        module::assert_module_data_validity,
+        namespace::Namespace,
+        salt::generate_instantiate_salt,
+        AccountId, AssetEntry, ABSTRACT_ACCOUNT_ID,
     },
     AbstractError,
 };
 use cosmwasm_std::{
-    ensure_eq, instantiate2_address, to_json_binary, Addr, Coins, CosmosMsg, Deps, DepsMut, Empty,
-    Env, MessageInfo, QuerierWrapper, SubMsg, SubMsgResult, WasmMsg,
+    ensure, ensure_eq, instantiate2_address, to_json_binary, Addr, Coins, CosmosMsg, Deps, DepsMut,
+    Empty, Env, MessageInfo, QuerierWrapper, Storage, SubMsg, SubMsgResult, WasmMsg,
 };
+use semver::Version;
 
 use crate::{
     contract::{AccountFactoryResponse, AccountFactoryResult},
@@ -35,6 +42,8 @@ use crate::{
 };
 
 pub const CREATE_ACCOUNT_MANAGER_MSG_ID: u64 = 2u64;
+pub const CONFIG_CHANGE_NOTIFICATION_MSG_ID: u64 = 3u64;
+pub const MODULE_FACTORY_NOTIFICATION_MSG_ID: u64 = 4u64;
 
 /// Function that starts the creation of the Account
 #[allow(clippy::too_many_arguments)]
@@ -50,7 +59,15 @@ pub fn execute_create_account(
     base_asset: Option<AssetEntry>,
     install_modules: Vec<ModuleInstallConfig>,
     account_id: Option<AccountId>,
+    proxy_admin: Option<ManagerOrGovernance>,
+    linked_contracts: Vec<(String, String)>,
 ) -> AccountFactoryResult {
+    // Modules installed during a creation could re-enter the factory before the pending
+    // creation is validated, which would overwrite its context
+    ensure!(
+        !CONTEXT.exists(deps.storage),
+        AccountFactoryError::CreationInProgress {}
+    );
     let config = CONFIG.load(deps.storage)?;
     let abstract_registry = VersionControlContract::new(config.version_control_contract.clone());
 
@@ -67,6 +84,19 @@ pub fn execute_create_account(
             }
         )
     }
+    // Refuse accounts owned by blocked addresses
+    let owners = match &governance {
+        GovernanceDetails::SubAccount { manager, proxy } => vec![manager.clone(), proxy.clone()],
+        governance => governance.owner_address().into_iter().collect(),
+    };
+    for owner in owners {
+        ensure!(
+            !BLOCKED_OWNERS.has(deps.storage, &owner),
+            AccountFactoryError::OwnerBlocked {
+                owner: owner.into_string()
+            }
+        );
+    }
     // If an account_id is provided, assert the caller is the ibc host and return the account_id.
     // Else get the next account id and set the origin to local.
     let account_id = match account_id {
@@ -100,11 +130,40 @@ pub fn execute_create_account(
         None => generate_new_local_account_id(deps.as_ref(), &info)?,
     };
 
-    // Query version_control for code_id of Proxy and Module contract
-    let proxy_module: Module =
-        query_module(&deps.querier, &config.version_control_contract, PROXY)?;
-    let manager_module: Module =
-        query_module(&deps.querier, &config.version_control_contract, MANAGER)?;
+    // The bootstrap account must have the governance required by the factory
+    if account_id == ABSTRACT_ACCOUNT_ID {
+        if let Some(bootstrap_governance) = &config.bootstrap_governance {
+            assert_bootstrap_governance(deps.as_ref(), bootstrap_governance, &governance)?;
+        }
+    }
+
+    let mut validated_linked_contracts: Vec<(String, Addr)> =
+        Vec::with_capacity(linked_contracts.len());
+    for (label, address) in linked_contracts {
+        // Labels are emitted as `label:address`, so they can't contain the separator
+        ensure!(
+            !label.is_empty()
+                && !label.contains(':')
+                && !validated_linked_contracts.iter().any(|(l, _)| *l == label),
+            AccountFactoryError::InvalidLinkedContractLabel { label }
+        );
+        let address = deps.api.addr_validate(&address)?;
+        validated_linked_contracts.push((label, address));
+    }
+    let linked_contracts = validated_linked_contracts;
+    if !linked_contracts.is_empty() {
+        LINKED_CONTRACTS.save(deps.storage, &account_id, &linked_contracts)?;
+    }
+
+    // Query version_control for code_id of Proxy and Manager contract
+    let (proxy_module, manager_module) =
+        query_account_modules(&deps.querier, &config.version_control_contract)?;
+
+    assert_min_module_versions(
+        deps.as_ref(),
+        &config.version_control_contract,
+        &install_modules,
+    )?;
 
     let simulate_resp: SimulateInstallModulesResponse = deps.querier.query_wasm_smart(
         config.module_factory_address.to_string(),
@@ -113,6 +172,13 @@ pub fn execute_create_account(
         },
     )?;
     let funds_for_install = simulate_resp.total_required_funds;
+    // Derive the namespace from the account name if none is provided and derivation is enabled
+    let namespace = match namespace {
+        None if config.derive_namespace => {
+            Some(derive_namespace(&deps.querier, &abstract_registry, &name)?)
+        }
+        namespace => namespace,
+    };
     let funds_for_namespace_fee = if namespace.is_some() {
         abstract_registry
             .namespace_registration_fee(&deps.querier)?
@@ -176,6 +242,12 @@ pub fn execute_create_account(
         manager: manager_addr_human,
         proxy: proxy_addr_human,
     };
+    let proxy_admin = match proxy_admin.unwrap_or_default() {
+        ManagerOrGovernance::Manager => account_base.manager.clone(),
+        ManagerOrGovernance::Governance => governance
+            .owner_address()
+            .ok_or(AccountFactoryError::NoGovernanceOwner {})?,
+    };
     // save context for after-init check
     let context = Context {
         account_id,
@@ -220,6 +292,9 @@ pub fn execute_create_account(
     if let Some(base_asset) = base_asset {
         metadata_attributes.push(("base_asset", base_asset.to_string()))
     }
+    for (label, address) in linked_contracts {
+        metadata_attributes.push(("linked_contract", format!("{label}:{address}")))
+    }
 
     // The execution order here is important.
     // Installing modules on the manager account requires that:
@@ -247,7 +322,7 @@ pub fn execute_create_account(
     .add_message(WasmMsg::Instantiate2 {
         code_id: proxy_code_id,
         funds: funds_to_proxy.into_vec(),
-        admin: Some(account_base.manager.to_string()),
+        admin: Some(proxy_admin.into_string()),
         label: format!("Proxy of Account: {}", proxy_message.account_id),
         msg: to_json_binary(&proxy_message)?,
         salt: salt.clone(),
@@ -277,32 +352,211 @@ pub fn execute_create_account(
     )))
 }
 
+pub fn execute_update_blocked_owners(
+    deps: DepsMut,
+    info: MessageInfo,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> AccountFactoryResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    for owner in to_add {
+        let owner = deps.api.addr_validate(&owner)?;
+        BLOCKED_OWNERS.save(deps.storage, &owner, &())?;
+    }
+    for owner in to_remove {
+        let owner = deps.api.addr_validate(&owner)?;
+        BLOCKED_OWNERS.remove(deps.storage, &owner);
+    }
+
+    Ok(AccountFactoryResponse::action("update_blocked_owners"))
+}
+
+pub fn execute_update_min_module_version(
+    deps: DepsMut,
+    info: MessageInfo,
+    namespace: Option<String>,
+    version: Option<String>,
+) -> AccountFactoryResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let version = version
+        .map(|version| Version::parse(&version))
+        .transpose()
+        .map_err(AbstractError::from)?;
+    match (namespace, version) {
+        (Some(namespace), Some(version)) => {
+            MIN_MODULE_VERSIONS.save(deps.storage, &Namespace::new(&namespace)?, &version)?
+        }
+        (Some(namespace), None) => {
+            MIN_MODULE_VERSIONS.remove(deps.storage, &Namespace::new(&namespace)?)
+        }
+        (None, Some(version)) => GLOBAL_MIN_MODULE_VERSION.save(deps.storage, &version)?,
+        (None, None) => GLOBAL_MIN_MODULE_VERSION.remove(deps.storage),
+    }
+
+    Ok(AccountFactoryResponse::action("update_min_module_version"))
+}
+
+/// Asserts that the modules are at least at the minimum version of their namespace,
+/// or at the global minimum version if their namespace has none
+fn assert_min_module_versions(
+    deps: Deps,
+    version_control: &Addr,
+    install_modules: &[ModuleInstallConfig],
+) -> AccountFactoryResult<()> {
+    let global_min_version = GLOBAL_MIN_MODULE_VERSION.may_load(deps.storage)?;
+    if install_modules.is_empty()
+        || (global_min_version.is_none() && MIN_MODULE_VERSIONS.is_empty(deps.storage))
+    {
+        return Ok(());
+    }
+
+    // Resolve the version of modules that are installed at their latest version
+    let modules: ModulesResponse = deps.querier.query_wasm_smart(
+        version_control,
+        &VCQuery::Modules {
+            infos: install_modules.iter().map(|m| m.module.clone()).collect(),
+        },
+    )?;
+    for ModuleResponse { module, .. } in modules.modules {
+        let Some(min_version) = MIN_MODULE_VERSIONS
+            .may_load(deps.storage, &module.info.namespace)?
+            .or_else(|| global_min_version.clone())
+        else {
+            continue;
+        };
+        let version: Version = module.info.version.clone().try_into()?;
+        ensure!(
+            version >= min_version,
+            AccountFactoryError::ModuleVersionTooOld {
+                module: module.info.id(),
+                version: version.to_string(),
+                min_version: min_version.to_string(),
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Asserts that the governance of the bootstrap account matches the required governance
+fn assert_bootstrap_governance(
+    deps: Deps,
+    bootstrap_governance: &BootstrapGovernance,
+    governance: &GovernanceDetails<Addr>,
+) -> AccountFactoryResult<()> {
+    let conforms = match (bootstrap_governance, governance) {
+        (BootstrapGovernance::FactoryOwner, GovernanceDetails::Monarchy { monarch }) => {
+            cw_ownable::is_owner(deps.storage, monarch)?
+        }
+        (
+            BootstrapGovernance::External { governance_address },
+            GovernanceDetails::External {
+                governance_address: address,
+                ..
+            },
+        ) => address == governance_address,
+        _ => false,
+    };
+    ensure!(conforms, AccountFactoryError::InvalidBootstrapGovernance {});
+    Ok(())
+}
+
 // Generate new local account id
 fn generate_new_local_account_id(
     deps: Deps,
     info: &MessageInfo,
 ) -> Result<AccountId, AccountFactoryError> {
     let origin = AccountTrace::Local;
-    let next_sequence = LOCAL_ACCOUNT_SEQUENCE.may_load(deps.storage)?.unwrap_or(0);
+    let next_sequence = next_local_sequence(deps.storage)?;
     if next_sequence == ABSTRACT_ACCOUNT_ID.seq() {
         cw_ownable::assert_owner(deps.storage, &info.sender)?;
     }
     Ok(AccountId::new(next_sequence, origin)?)
 }
 
-fn query_module(
+/// Next local account sequence, aligned to the sequence offset and stride of this factory
+pub(crate) fn next_local_sequence(storage: &dyn Storage) -> AccountFactoryResult<AccountSequence> {
+    let Config {
+        sequence_offset,
+        sequence_stride,
+        ..
+    } = CONFIG.load(storage)?;
+    let sequence = LOCAL_ACCOUNT_SEQUENCE.may_load(storage)?.unwrap_or(0);
+    if sequence <= sequence_offset {
+        return Ok(sequence_offset);
+    }
+    // Round up to the next sequence in the range of this factory
+    let steps = (sequence - sequence_offset).div_ceil(sequence_stride);
+    steps
+        .checked_mul(sequence_stride)
+        .and_then(|sequence| sequence.checked_add(sequence_offset))
+        .ok_or(AccountFactoryError::AccountSequenceOverflow {})
+}
+
+/// Asserts that the sequence range of this factory is valid and that it can create at least one account
+pub(crate) fn assert_sequence_range(config: &Config) -> AccountFactoryResult<()> {
+    ensure!(
+        config.sequence_stride > 0,
+        AccountFactoryError::InvalidSequenceStride {}
+    );
+    ensure!(
+        config
+            .sequence_offset
+            .checked_add(config.sequence_stride)
+            .is_some(),
+        AccountFactoryError::InvalidSequenceRange {
+            offset: config.sequence_offset,
+            stride: config.sequence_stride,
+        }
+    );
+    Ok(())
+}
+
+/// Slugifies the account name into a namespace and asserts that it's still available.
+fn derive_namespace(
+    querier: &QuerierWrapper,
+    abstract_registry: &VersionControlContract,
+    name: &str,
+) -> AccountFactoryResult<String> {
+    let slug = name
+        .to_ascii_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let namespace = Namespace::new(&slug)?;
+
+    if let NamespaceResponse::Claimed(_) =
+        abstract_registry.query_namespace(namespace.clone(), querier)?
+    {
+        return Err(AccountFactoryError::DerivedNamespaceClaimed {
+            name: name.to_owned(),
+            namespace: namespace.to_string(),
+        });
+    }
+    Ok(namespace.to_string())
+}
+
+/// Queries the latest proxy and manager modules from version control in a single query
+pub(crate) fn query_account_modules(
     querier: &QuerierWrapper,
     version_control_addr: &Addr,
-    module_id: &str,
-) -> AbstractResult<Module> {
+) -> AbstractResult<(Module, Module)> {
     let ModulesResponse { mut modules } = querier.query_wasm_smart(
         version_control_addr.to_string(),
         &VCQuery::Modules {
-            infos: vec![ModuleInfo::from_id_latest(module_id)?],
+            infos: vec![
+                ModuleInfo::from_id_latest(PROXY)?,
+                ModuleInfo::from_id_latest(MANAGER)?,
+            ],
         },
     )?;
 
-    Ok(modules.swap_remove(0).module)
+    // modules are returned in the order they were queried in
+    let manager_module = modules.swap_remove(1).module;
+    let proxy_module = modules.swap_remove(0).module;
+    Ok((proxy_module, manager_module))
 }
 
 /// Validates instantiated manager and proxy modules
@@ -325,11 +579,35 @@ pub fn validate_instantiated_account(deps: DepsMut, _result: SubMsgResult) -> Ac
         Some(account_base.proxy.clone()),
     )?;
 
-    // Add 1 to account sequence for local origin
+    let config = CONFIG.load(deps.storage)?;
+    // Move the account sequence to the next one of this factory for local origin
     if account_id.is_local() {
-        LOCAL_ACCOUNT_SEQUENCE.save(deps.storage, &account_id.seq().checked_add(1).unwrap())?;
+        LOCAL_ACCOUNT_SEQUENCE.save(
+            deps.storage,
+            &account_id
+                .seq()
+                .checked_add(config.sequence_stride)
+                .ok_or(AccountFactoryError::AccountSequenceOverflow {})?,
+        )?;
     }
 
+    // Notify the module factory without blocking the creation if it fails
+    let notification = if config.notify_module_factory {
+        Some(SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: config.module_factory_address.to_string(),
+                msg: to_json_binary(&abstract_std::module_factory::ExecuteMsg::AccountCreated {
+                    account_id: account_id.clone(),
+                    account_base: account_base.clone(),
+                })?,
+                funds: vec![],
+            },
+            MODULE_FACTORY_NOTIFICATION_MSG_ID,
+        ))
+    } else {
+        None
+    };
+
     let resp = AccountFactoryResponse::new(
         "create_account",
         vec![
@@ -337,7 +615,8 @@ pub fn validate_instantiated_account(deps: DepsMut, _result: SubMsgResult) -> Ac
             ("manager_address", account_base.manager.into_string()),
             ("proxy_address", account_base.proxy.into_string()),
         ],
-    );
+    )
+    .add_submessages(notification);
 
     Ok(resp)
 }
@@ -351,10 +630,14 @@ pub fn execute_update_config(
     version_control_contract: Option<String>,
     module_factory_address: Option<String>,
     ibc_host: Option<String>,
+    derive_namespace: Option<bool>,
+    config_change_subscriber: Option<Clearable<String>>,
+    notify_module_factory: Option<bool>,
 ) -> AccountFactoryResult {
     cw_ownable::assert_owner(deps.storage, &info.sender)?;
 
-    let mut config: Config = CONFIG.load(deps.storage)?;
+    let previous_config: Config = CONFIG.load(deps.storage)?;
+    let mut config = previous_config.clone();
 
     if let Some(ans_host_contract) = ans_host_contract {
         // validate address format
@@ -375,7 +658,36 @@ pub fn execute_update_config(
         // validate address format
         config.ibc_host = Some(deps.api.addr_validate(&ibc_host)?);
     }
+
+    if let Some(derive_namespace) = derive_namespace {
+        config.derive_namespace = derive_namespace;
+    }
+
+    if let Some(config_change_subscriber) = config_change_subscriber {
+        let subscriber: Option<String> = config_change_subscriber.into();
+        config.config_change_subscriber = subscriber
+            .map(|subscriber| deps.api.addr_validate(&subscriber))
+            .transpose()?;
+    }
+
+    if let Some(notify_module_factory) = notify_module_factory {
+        config.notify_module_factory = notify_module_factory;
+    }
     CONFIG.save(deps.storage, &config)?;
 
-    Ok(AccountFactoryResponse::action("update_config"))
+    let mut response = AccountFactoryResponse::action("update_config");
+    // Notify the subscriber when the config actually changed
+    if let Some(subscriber) = &config.config_change_subscriber {
+        if config != previous_config {
+            response = response.add_submessage(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: subscriber.to_string(),
+                    msg: to_json_binary(&ConfigChangeSubscriberMsg::ConfigChanged {})?,
+                    funds: vec![],
+                },
+                CONFIG_CHANGE_NOTIFICATION_MSG_ID,
+            ));
+        }
+    }
+    Ok(response)
 }
@@ -3,10 +3,8 @@ use abstract_sdk::{
     execute_update_ownership, query_ownership,
     std::{account_factory::*, ACCOUNT_FACTORY},
 };
-use abstract_std::objects::module_version::assert_contract_upgrade;
-use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
-};
+use abstract_std::objects::{module_version::assert_contract_upgrade, namespace::Namespace};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
 use semver::Version;
 
 use crate::{commands, error::AccountFactoryError, queries, state::*};
@@ -30,14 +28,41 @@ pub fn instantiate(
         module_factory_address: deps.api.addr_validate(&msg.module_factory_address)?,
         ans_host_contract: deps.api.addr_validate(&msg.ans_host_address)?,
         ibc_host: None,
+        derive_namespace: false,
+        sequence_offset: msg.sequence_offset.unwrap_or_default(),
+        sequence_stride: msg.sequence_stride.unwrap_or(1),
+        event_namespace: msg
+            .event_namespace
+            .map(|namespace| Namespace::new(&namespace).map(|n| n.to_string()))
+            .transpose()?,
+        bootstrap_governance: msg
+            .bootstrap_governance
+            .map(|governance| governance.check(deps.api))
+            .transpose()?,
+        config_change_subscriber: None,
+        notify_module_factory: false,
     };
+    commands::assert_sequence_range(&config)?;
 
     cw2::set_contract_version(deps.storage, ACCOUNT_FACTORY, CONTRACT_VERSION)?;
 
     CONFIG.save(deps.storage, &config)?;
     // Set up the admin
     cw_ownable::initialize_owner(deps.storage, deps.api, Some(&msg.admin))?;
-    Ok(AccountFactoryResponse::action("instantiate"))
+    Ok(with_event_namespace(
+        AccountFactoryResponse::action("instantiate"),
+        config.event_namespace.as_deref(),
+    ))
+}
+
+/// Prefixes the type of the response's events with the configured event namespace.
+fn with_event_namespace(mut response: Response, event_namespace: Option<&str>) -> Response {
+    if let Some(event_namespace) = event_namespace {
+        for event in response.events.iter_mut() {
+            event.ty = format!("{event_namespace}-{}", event.ty);
+        }
+    }
+    response
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
@@ -47,12 +72,16 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> AccountFactoryResult {
-    match msg {
+    let event_namespace = CONFIG.load(deps.storage)?.event_namespace;
+    let response = match msg {
         ExecuteMsg::UpdateConfig {
             ans_host_contract,
             version_control_contract,
             module_factory_address,
             ibc_host,
+            derive_namespace,
+            config_change_subscriber,
+            notify_module_factory,
         } => commands::execute_update_config(
             deps,
             info,
@@ -60,6 +89,9 @@ pub fn execute(
             version_control_contract,
             module_factory_address,
             ibc_host,
+            derive_namespace,
+            config_change_subscriber,
+            notify_module_factory,
         ),
         ExecuteMsg::CreateAccount {
             governance,
@@ -70,6 +102,8 @@ pub fn execute(
             namespace,
             base_asset,
             install_modules,
+            proxy_admin,
+            linked_contracts,
         } => commands::execute_create_account(
             deps,
             env,
@@ -82,47 +116,110 @@ pub fn execute(
             base_asset,
             install_modules,
             account_id,
+            proxy_admin,
+            linked_contracts.unwrap_or_default(),
         ),
+        ExecuteMsg::UpdateBlockedOwners { to_add, to_remove } => {
+            commands::execute_update_blocked_owners(deps, info, to_add, to_remove)
+        }
+        ExecuteMsg::UpdateMinModuleVersion { namespace, version } => {
+            commands::execute_update_min_module_version(deps, info, namespace, version)
+        }
         ExecuteMsg::UpdateOwnership(action) => {
             execute_update_ownership!(AccountFactoryResponse, deps, env, info, action)
         }
-    }
+    }?;
+    Ok(with_event_namespace(response, event_namespace.as_deref()))
 }
 
 /// This just stores the result for future query
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> AccountFactoryResult {
-    match msg {
+    let event_namespace = CONFIG.load(deps.storage)?.event_namespace;
+    let response = match msg {
         Reply {
             id: commands::CREATE_ACCOUNT_MANAGER_MSG_ID,
             result,
         } => commands::validate_instantiated_account(deps, result),
+        // Notifying the subscriber and the module factory is fire-and-forget
+        Reply {
+            id: commands::MODULE_FACTORY_NOTIFICATION_MSG_ID,
+            ..
+        } => Ok(AccountFactoryResponse::action(
+            "module_factory_notification_failed",
+        )),
+        Reply {
+            id: commands::CONFIG_CHANGE_NOTIFICATION_MSG_ID,
+            ..
+        } => Ok(AccountFactoryResponse::action(
+            "config_change_notification_failed",
+        )),
         _ => Err(AccountFactoryError::UnexpectedReply {}),
-    }
+    }?;
+    Ok(with_event_namespace(response, event_namespace.as_deref()))
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> AccountFactoryResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&queries::query_config(deps)?),
+        QueryMsg::SimulateInstallModules { modules } => {
+            to_json_binary(&queries::query_simulate_install_modules(deps, modules)?)
+        }
+        QueryMsg::LinkedContracts { account_id } => {
+            to_json_binary(&queries::query_linked_contracts(deps, account_id)?)
+        }
+        QueryMsg::BlockedOwners { start_after, limit } => {
+            to_json_binary(&queries::query_blocked_owners(deps, start_after, limit)?)
+        }
+        QueryMsg::MinModuleVersion { namespace } => {
+            to_json_binary(&queries::query_min_module_version(deps, namespace)?)
+        }
         QueryMsg::Ownership {} => query_ownership!(deps),
     }
+    .map_err(Into::into)
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> AccountFactoryResult {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> AccountFactoryResult {
     let version: Version = CONTRACT_VERSION.parse().unwrap();
 
     assert_contract_upgrade(deps.storage, ACCOUNT_FACTORY, version)?;
+
+    let MigrateMsg {
+        sequence_offset,
+        sequence_stride,
+    } = msg;
+    let mut config = CONFIG.load(deps.storage)?;
+    if let Some(sequence_offset) = sequence_offset {
+        config.sequence_offset = sequence_offset;
+    }
+    if let Some(sequence_stride) = sequence_stride {
+        config.sequence_stride = sequence_stride;
+    }
+    commands::assert_sequence_range(&config)?;
+    CONFIG.save(deps.storage, &config)?;
     cw2::set_contract_version(deps.storage, ACCOUNT_FACTORY, CONTRACT_VERSION)?;
 
-    Ok(AccountFactoryResponse::action("migrate"))
+    Ok(with_event_namespace(
+        AccountFactoryResponse::action("migrate"),
+        config.event_namespace.as_deref(),
+    ))
 }
 
 #[cfg(test)]
 mod tests {
+    use abstract_std::{
+        objects::{
+            gov_type::GovernanceDetails,
+            module::{Module, ModuleInfo},
+            module_reference::ModuleReference,
+        },
+        version_control::AccountBase,
+        MANAGER, PROXY,
+    };
     use abstract_testing::prelude::*;
-    use cosmwasm_std::{testing::*, Addr};
+    use cosmwasm_std::{coin, coins, testing::*, Addr, StdResult};
     use cw_ownable::OwnershipError;
     use speculoos::prelude::*;
 
@@ -154,6 +251,9 @@ mod tests {
     }
 
     mod update_config {
+        use abstract_sdk::cw_helpers::Clearable;
+        use cosmwasm_std::{SubMsg, WasmMsg};
+
         use super::*;
 
         #[test]
@@ -167,6 +267,9 @@ mod tests {
                 version_control_contract: None,
                 module_factory_address: None,
                 ibc_host: None,
+                derive_namespace: None,
+                config_change_subscriber: None,
+                notify_module_factory: None,
             };
 
             test_only_owner(deps.as_mut(), msg)?;
@@ -185,6 +288,9 @@ mod tests {
                 version_control_contract: None,
                 module_factory_address: None,
                 ibc_host: None,
+                derive_namespace: None,
+                config_change_subscriber: None,
+                notify_module_factory: None,
             };
 
             execute_as_owner(deps.as_mut(), msg)?;
@@ -194,6 +300,13 @@ mod tests {
                 ans_host_contract: Addr::unchecked(new_ans_host),
                 module_factory_address: Addr::unchecked(TEST_MODULE_FACTORY),
                 ibc_host: None,
+                derive_namespace: false,
+                sequence_offset: 0,
+                sequence_stride: 1,
+                event_namespace: None,
+                bootstrap_governance: None,
+                config_change_subscriber: None,
+                notify_module_factory: false,
             };
             let actual_config: Config = CONFIG.load(deps.as_ref().storage)?;
             assert_that!(actual_config).is_equal_to(expected_config);
@@ -212,6 +325,9 @@ mod tests {
                 version_control_contract: Some(new_version_control.to_string()),
                 module_factory_address: None,
                 ibc_host: None,
+                derive_namespace: None,
+                config_change_subscriber: None,
+                notify_module_factory: None,
             };
 
             execute_as_owner(deps.as_mut(), msg)?;
@@ -221,6 +337,13 @@ mod tests {
                 ans_host_contract: Addr::unchecked(TEST_ANS_HOST),
                 module_factory_address: Addr::unchecked(TEST_MODULE_FACTORY),
                 ibc_host: None,
+                derive_namespace: false,
+                sequence_offset: 0,
+                sequence_stride: 1,
+                event_namespace: None,
+                bootstrap_governance: None,
+                config_change_subscriber: None,
+                notify_module_factory: false,
             };
             let actual_config: Config = CONFIG.load(deps.as_ref().storage)?;
             assert_that!(actual_config).is_equal_to(expected_config);
@@ -239,6 +362,9 @@ mod tests {
                 version_control_contract: None,
                 module_factory_address: Some(new_module_factory.to_string()),
                 ibc_host: None,
+                derive_namespace: None,
+                config_change_subscriber: None,
+                notify_module_factory: None,
             };
 
             execute_as_owner(deps.as_mut(), msg)?;
@@ -248,6 +374,13 @@ mod tests {
                 ans_host_contract: Addr::unchecked(TEST_ANS_HOST),
                 module_factory_address: Addr::unchecked(new_module_factory),
                 ibc_host: None,
+                derive_namespace: false,
+                sequence_offset: 0,
+                sequence_stride: 1,
+                event_namespace: None,
+                bootstrap_governance: None,
+                config_change_subscriber: None,
+                notify_module_factory: false,
             };
             let actual_config: Config = CONFIG.load(deps.as_ref().storage)?;
             assert_that!(actual_config).is_equal_to(expected_config);
@@ -268,6 +401,9 @@ mod tests {
                 version_control_contract: Some(new_version_control.to_string()),
                 module_factory_address: Some(new_module_factory.to_string()),
                 ibc_host: None,
+                derive_namespace: None,
+                config_change_subscriber: None,
+                notify_module_factory: None,
             };
 
             execute_as_owner(deps.as_mut(), msg)?;
@@ -277,12 +413,68 @@ mod tests {
                 ans_host_contract: Addr::unchecked(new_ans_host),
                 module_factory_address: Addr::unchecked(new_module_factory),
                 ibc_host: None,
+                derive_namespace: false,
+                sequence_offset: 0,
+                sequence_stride: 1,
+                event_namespace: None,
+                bootstrap_governance: None,
+                config_change_subscriber: None,
+                notify_module_factory: false,
             };
             let actual_config: Config = CONFIG.load(deps.as_ref().storage)?;
             assert_that!(actual_config).is_equal_to(expected_config);
 
             Ok(())
         }
+
+        #[test]
+        fn notifies_config_change_subscriber() -> AccountFactoryTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let subscriber = "config_subscriber";
+            let update_msg = |ans_host_contract: &str| ExecuteMsg::UpdateConfig {
+                ans_host_contract: Some(ans_host_contract.to_string()),
+                version_control_contract: None,
+                module_factory_address: None,
+                ibc_host: None,
+                derive_namespace: None,
+                config_change_subscriber: None,
+                notify_module_factory: None,
+            };
+            let notification = SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: subscriber.to_string(),
+                    msg: to_json_binary(&ConfigChangeSubscriberMsg::ConfigChanged {})?,
+                    funds: vec![],
+                },
+                commands::CONFIG_CHANGE_NOTIFICATION_MSG_ID,
+            );
+
+            let res = execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::UpdateConfig {
+                    ans_host_contract: None,
+                    version_control_contract: None,
+                    module_factory_address: None,
+                    ibc_host: None,
+                    derive_namespace: None,
+                    config_change_subscriber: Some(Clearable::Set(subscriber.to_string())),
+                    notify_module_factory: None,
+                },
+            )?;
+            assert_that!(res.messages).is_equal_to(vec![notification.clone()]);
+
+            // Subscriber is notified of a real change
+            let res = execute_as_owner(deps.as_mut(), update_msg("test_ans_host_2"))?;
+            assert_that!(res.messages).is_equal_to(vec![notification]);
+
+            // But not of a no-op update
+            let res = execute_as_owner(deps.as_mut(), update_msg("test_ans_host_2"))?;
+            assert_that!(res.messages).is_empty();
+
+            Ok(())
+        }
     }
 
     mod update_ownership {
@@ -333,6 +525,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn create_account_while_creation_in_progress() -> AccountFactoryTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(deps.as_mut())?;
+
+        // A creation is pending until its manager instantiation is validated
+        CONTEXT.save(
+            deps.as_mut().storage,
+            &Context {
+                account_base: AccountBase {
+                    manager: Addr::unchecked("manager"),
+                    proxy: Addr::unchecked("proxy"),
+                },
+                manager_module: Module {
+                    info: ModuleInfo::from_id_latest(MANAGER)?,
+                    reference: ModuleReference::AccountBase(1),
+                },
+                proxy_module: Module {
+                    info: ModuleInfo::from_id_latest(PROXY)?,
+                    reference: ModuleReference::AccountBase(2),
+                },
+                account_id: TEST_ACCOUNT_ID,
+            },
+        )?;
+
+        // so a re-entrant creation is rejected
+        let msg = ExecuteMsg::CreateAccount {
+            governance: GovernanceDetails::Monarchy {
+                monarch: OWNER.to_owned(),
+            },
+            name: "account".to_owned(),
+            base_asset: None,
+            description: None,
+            link: None,
+            linked_contracts: None,
+            account_id: None,
+            namespace: None,
+            install_modules: vec![],
+            proxy_admin: None,
+        };
+        let res = execute_as(deps.as_mut(), OWNER, msg);
+        assert_that!(&res)
+            .is_err()
+            .is_equal_to(AccountFactoryError::CreationInProgress {});
+
+        Ok(())
+    }
+
+    #[test]
+    fn next_local_sequence_overflow() -> AccountFactoryTestResult {
+        let mut deps = mock_dependencies();
+        mock_init(deps.as_mut())?;
+        CONFIG.update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+            config.sequence_stride = 2;
+            Ok(config)
+        })?;
+
+        // the next sequence in the range of this factory doesn't fit in an account sequence
+        LOCAL_ACCOUNT_SEQUENCE.save(deps.as_mut().storage, &u32::MAX)?;
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {});
+        assert_that!(res)
+            .is_err()
+            .is_equal_to(AccountFactoryError::AccountSequenceOverflow {});
+
+        Ok(())
+    }
+
+    #[test]
+    fn query_account_modules_batched() -> AccountFactoryTestResult {
+        let mut deps = mock_dependencies();
+        deps.querier = MockQuerierBuilder::default()
+            .with_smart_handler(TEST_VERSION_CONTROL, |msg| match from_json(msg).unwrap() {
+                abstract_std::version_control::QueryMsg::Modules { infos } => {
+                    let modules = infos
+                        .into_iter()
+                        .map(|info| {
+                            let code_id = if info.id() == PROXY { 1 } else { 2 };
+                            abstract_std::version_control::ModuleResponse {
+                                module: Module {
+                                    info,
+                                    reference: ModuleReference::AccountBase(code_id),
+                                },
+                                config: Default::default(),
+                            }
+                        })
+                        .collect();
+                    let resp = abstract_std::version_control::ModulesResponse { modules };
+                    Ok(to_json_binary(&resp).unwrap())
+                }
+                _ => panic!("unexpected message"),
+            })
+            .build();
+        mock_init(deps.as_mut())?;
+
+        let query_module = |module_id: &str| -> AccountFactoryResult<Module> {
+            let abstract_std::version_control::ModulesResponse { mut modules } =
+                deps.as_ref().querier.query_wasm_smart(
+                    TEST_VERSION_CONTROL,
+                    &abstract_std::version_control::QueryMsg::Modules {
+                        infos: vec![ModuleInfo::from_id_latest(module_id)?],
+                    },
+                )?;
+            Ok(modules.swap_remove(0).module)
+        };
+
+        // The batched query returns the same modules as querying them one by one
+        let (proxy_module, manager_module) = crate::commands::query_account_modules(
+            &deps.as_ref().querier,
+            &Addr::unchecked(TEST_VERSION_CONTROL),
+        )?;
+        assert_that!(proxy_module).is_equal_to(query_module(PROXY)?);
+        assert_that!(manager_module).is_equal_to(query_module(MANAGER)?);
+
+        Ok(())
+    }
+
     #[test]
     fn query_config() -> AccountFactoryTestResult {
         let mut deps = mock_dependencies();
@@ -348,6 +656,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn query_simulate_install_modules() -> AccountFactoryTestResult {
+        let mut deps = mock_dependencies();
+        deps.querier = MockQuerierBuilder::default()
+            .with_smart_handler(TEST_MODULE_FACTORY, |msg| match from_json(msg).unwrap() {
+                abstract_std::module_factory::QueryMsg::SimulateInstallModules { modules } => {
+                    let resp = abstract_std::module_factory::SimulateInstallModulesResponse {
+                        total_required_funds: coins(15, "ucosm"),
+                        monetization_funds: vec![(modules[0].id(), coin(10, "ucosm"))],
+                        initialization_funds: vec![(modules[0].id(), coins(5, "ucosm"))],
+                    };
+                    Ok(to_json_binary(&resp).unwrap())
+                }
+                _ => panic!("unexpected message"),
+            })
+            .build();
+        mock_init(deps.as_mut())?;
+
+        let modules = vec![ModuleInfo::from_id_latest("test:module")?];
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::SimulateInstallModules { modules },
+        )?;
+        let simulation: abstract_std::module_factory::SimulateInstallModulesResponse =
+            from_json(res).unwrap();
+
+        assert_that!(simulation).is_equal_to(
+            abstract_std::module_factory::SimulateInstallModulesResponse {
+                total_required_funds: coins(15, "ucosm"),
+                monetization_funds: vec![("test:module".to_owned(), coin(10, "ucosm"))],
+                initialization_funds: vec![("test:module".to_owned(), coins(5, "ucosm"))],
+            },
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn query_ownership() -> AccountFactoryTestResult {
         let mut deps = mock_dependencies();
@@ -376,7 +722,7 @@ mod tests {
 
             let version: Version = CONTRACT_VERSION.parse().unwrap();
 
-            let res = contract::migrate(deps.as_mut(), mock_env(), MigrateMsg {});
+            let res = contract::migrate(deps.as_mut(), mock_env(), MigrateMsg::default());
 
             assert_that!(res)
                 .is_err()
@@ -401,7 +747,7 @@ mod tests {
 
             let version: Version = CONTRACT_VERSION.parse().unwrap();
 
-            let res = contract::migrate(deps.as_mut(), mock_env(), MigrateMsg {});
+            let res = contract::migrate(deps.as_mut(), mock_env(), MigrateMsg::default());
 
             assert_that!(res)
                 .is_err()
@@ -425,7 +771,7 @@ mod tests {
             let old_name = "old:contract";
             cw2::set_contract_version(deps.as_mut().storage, old_name, old_version)?;
 
-            let res = contract::migrate(deps.as_mut(), mock_env(), MigrateMsg {});
+            let res = contract::migrate(deps.as_mut(), mock_env(), MigrateMsg::default());
 
             assert_that!(res)
                 .is_err()
@@ -453,12 +799,69 @@ mod tests {
             .to_string();
             cw2::set_contract_version(deps.as_mut().storage, ACCOUNT_FACTORY, small_version)?;
 
-            let res = contract::migrate(deps.as_mut(), mock_env(), MigrateMsg {})?;
+            let res = contract::migrate(deps.as_mut(), mock_env(), MigrateMsg::default())?;
             assert_that!(res.messages).has_length(0);
 
             assert_that!(cw2::get_contract_version(&deps.storage)?.version)
                 .is_equal_to(version.to_string());
             Ok(())
         }
+
+        #[test]
+        fn updates_sequence_range() -> AccountFactoryResult<()> {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let version: Version = CONTRACT_VERSION.parse().unwrap();
+            let small_version = Version {
+                minor: version.minor - 1,
+                ..version
+            }
+            .to_string();
+            cw2::set_contract_version(deps.as_mut().storage, ACCOUNT_FACTORY, small_version)?;
+
+            // stride of zero is invalid
+            let res = contract::migrate(
+                deps.as_mut(),
+                mock_env(),
+                MigrateMsg {
+                    sequence_offset: None,
+                    sequence_stride: Some(0),
+                },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AccountFactoryError::InvalidSequenceStride {});
+
+            // offset must leave room for at least one account
+            let res = contract::migrate(
+                deps.as_mut(),
+                mock_env(),
+                MigrateMsg {
+                    sequence_offset: Some(u32::MAX),
+                    sequence_stride: None,
+                },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AccountFactoryError::InvalidSequenceRange {
+                    offset: u32::MAX,
+                    stride: 1,
+                });
+
+            contract::migrate(
+                deps.as_mut(),
+                mock_env(),
+                MigrateMsg {
+                    sequence_offset: Some(1),
+                    sequence_stride: Some(2),
+                },
+            )?;
+
+            let config = CONFIG.load(deps.as_ref().storage)?;
+            assert_that!(config.sequence_offset).is_equal_to(1);
+            assert_that!(config.sequence_stride).is_equal_to(2);
+            Ok(())
+        }
     }
 }
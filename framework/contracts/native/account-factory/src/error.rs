@@ -1,6 +1,6 @@
 use abstract_sdk::AbstractSdkError;
 use abstract_std::{
-    objects::{version_control::VersionControlError, AccountId},
+    objects::{account::AccountSequence, version_control::VersionControlError, AccountId},
     AbstractError,
 };
 use cosmwasm_std::{Instantiate2AddressError, StdError};
@@ -58,6 +58,47 @@ pub enum AccountFactoryError {
     #[error("The caller ({caller}) is not the owner account's manager ({manager}). Only manager can create sub-accounts for its account.", )]
     SubAccountCreatorNotManager { caller: String, manager: String },
 
+    #[error("The account's governance has no owner that can be set as proxy admin")]
+    NoGovernanceOwner {},
+
+    #[error("Namespace \"{namespace}\" derived from account name \"{name}\" is already claimed. Provide a namespace explicitly.")]
+    DerivedNamespaceClaimed { name: String, namespace: String },
+
+    #[error("Account sequence stride must be at least 1")]
+    InvalidSequenceStride {},
+
+    #[error(
+        "Account sequence offset {offset} with stride {stride} exceeds the account sequence range"
+    )]
+    InvalidSequenceRange {
+        offset: AccountSequence,
+        stride: AccountSequence,
+    },
+
+    #[error("The account sequences of this factory are exhausted")]
+    AccountSequenceOverflow {},
+
+    #[error("Another account creation is in progress")]
+    CreationInProgress {},
+
+    #[error("Owner {owner} is blocked from owning accounts")]
+    OwnerBlocked { owner: String },
+
+    #[error("Module {module} version {version} is older than the minimum version {min_version}")]
+    ModuleVersionTooOld {
+        module: String,
+        version: String,
+        min_version: String,
+    },
+
+    #[error(
+        "Governance of the bootstrap account doesn't match the governance required by the factory"
+    )]
+    InvalidBootstrapGovernance {},
+
+    #[error("Linked contract label \"{label}\" is invalid: labels must be non-empty, unique and can't contain ':'")]
+    InvalidLinkedContractLabel { label: String },
+
     #[error("Expected local account id doesn't match, expected: {predicted}, actual: {actual} Try again.")]
     ExpectedAccountIdFailed {
         predicted: AccountId,
@@ -1,18 +1,87 @@
-use abstract_sdk::std::account_factory::*;
-use cosmwasm_std::{Deps, StdResult};
+use abstract_sdk::std::{
+    account_factory::*,
+    module_factory::{QueryMsg as ModuleFactoryQuery, SimulateInstallModulesResponse},
+    objects::{module::ModuleInfo, namespace::Namespace, AccountId},
+};
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
 
-use crate::state::*;
+use crate::{commands::next_local_sequence, contract::AccountFactoryResult, state::*};
 
-pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+const DEFAULT_LIMIT: u8 = 10;
+const MAX_LIMIT: u8 = 20;
+
+pub fn query_config(deps: Deps) -> AccountFactoryResult<ConfigResponse> {
     let state: Config = CONFIG.load(deps.storage)?;
     let _admin = cw_ownable::get_ownership(deps.storage)?;
     let resp = ConfigResponse {
         version_control_contract: state.version_control_contract,
         ans_host_contract: state.ans_host_contract,
         module_factory_address: state.module_factory_address,
-        local_account_sequence: LOCAL_ACCOUNT_SEQUENCE.may_load(deps.storage)?.unwrap_or(0),
+        local_account_sequence: next_local_sequence(deps.storage)?,
         ibc_host: state.ibc_host,
+        derive_namespace: state.derive_namespace,
+        sequence_offset: state.sequence_offset,
+        sequence_stride: state.sequence_stride,
+        event_namespace: state.event_namespace,
+        bootstrap_governance: state.bootstrap_governance,
+        config_change_subscriber: state.config_change_subscriber,
+        notify_module_factory: state.notify_module_factory,
     };
 
     Ok(resp)
 }
+
+pub fn query_simulate_install_modules(
+    deps: Deps,
+    modules: Vec<ModuleInfo>,
+) -> StdResult<SimulateInstallModulesResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    deps.querier.query_wasm_smart(
+        config.module_factory_address,
+        &ModuleFactoryQuery::SimulateInstallModules { modules },
+    )
+}
+
+pub fn query_linked_contracts(
+    deps: Deps,
+    account_id: AccountId,
+) -> StdResult<LinkedContractsResponse> {
+    let linked_contracts = LINKED_CONTRACTS
+        .may_load(deps.storage, &account_id)?
+        .unwrap_or_default();
+    Ok(LinkedContractsResponse { linked_contracts })
+}
+
+pub fn query_blocked_owners(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<BlockedOwnersResponse> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let start_bound = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let owners = BLOCKED_OWNERS
+        .keys(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(BlockedOwnersResponse { owners })
+}
+
+pub fn query_min_module_version(
+    deps: Deps,
+    namespace: Option<String>,
+) -> StdResult<MinModuleVersionResponse> {
+    let version = match namespace {
+        Some(namespace) => {
+            MIN_MODULE_VERSIONS.may_load(deps.storage, &Namespace::unchecked(namespace))?
+        }
+        None => GLOBAL_MIN_MODULE_VERSION.may_load(deps.storage)?,
+    };
+    Ok(MinModuleVersionResponse {
+        version: version.map(|version| version.to_string()),
+    })
+}
@@ -1 +1,63 @@
+use abstract_interface::{AccountFactory, AccountFactoryExecFns};
+use abstract_std::{
+    account_factory::ManagerOrGovernance,
+    manager::ModuleInstallConfig,
+    objects::{gov_type::GovernanceDetails, AccountId},
+};
+use cw_orch::prelude::*;
 
+/// Account creation through the account factory.
+/// Tests only set the fields they exercise, the others keep their defaults:
+/// ```ignore
+/// CreateAccount {
+///     namespace: Some("namespace".to_owned()),
+///     ..CreateAccount::monarchy(&owner)
+/// }
+/// .create(factory)?;
+/// ```
+pub struct CreateAccount {
+    pub governance: GovernanceDetails<String>,
+    pub name: String,
+    pub install_modules: Vec<ModuleInstallConfig>,
+    pub account_id: Option<AccountId>,
+    pub linked_contracts: Option<Vec<(String, String)>>,
+    pub namespace: Option<String>,
+    pub proxy_admin: Option<ManagerOrGovernance>,
+}
+
+impl CreateAccount {
+    pub fn new(governance: GovernanceDetails<String>) -> Self {
+        Self {
+            governance,
+            name: "account".to_owned(),
+            install_modules: vec![],
+            account_id: None,
+            linked_contracts: None,
+            namespace: None,
+            proxy_admin: None,
+        }
+    }
+
+    /// Account owned by `monarch`
+    pub fn monarchy(monarch: &Addr) -> Self {
+        Self::new(GovernanceDetails::Monarchy {
+            monarch: monarch.to_string(),
+        })
+    }
+
+    pub fn create(self, factory: &AccountFactory<MockBech32>) -> Result<AppResponse, CwOrchError> {
+        factory.create_account(
+            self.governance,
+            self.install_modules,
+            self.name,
+            self.account_id,
+            None,
+            None,
+            None,
+            self.linked_contracts,
+            self.namespace,
+            self.proxy_admin,
+            &[],
+        )
+    }
+}
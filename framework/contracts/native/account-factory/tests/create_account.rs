@@ -1,19 +1,28 @@
 mod common;
 
+use abstract_integration_tests::mock_modules::{adapter_1, deploy_modules, V1, V2};
 use abstract_interface::{
     AbstractAccount, AccountFactoryExecFns, AccountFactoryQueryFns, VCQueryFns, *,
 };
+use abstract_sdk::cw_helpers::Clearable;
 use abstract_std::{
-    account_factory,
+    account_factory::{
+        self, BootstrapGovernance, ManagerOrGovernance, UncheckedBootstrapGovernance,
+    },
+    manager::ModuleInstallConfig,
     objects::{
-        account::AccountTrace, gov_type::GovernanceDetails, namespace::Namespace, AccountId,
-        AssetEntry,
+        account::AccountTrace,
+        gov_type::GovernanceDetails,
+        module::{ModuleInfo, ModuleVersion},
+        namespace::Namespace,
+        AccountId, AssetEntry, ABSTRACT_ACCOUNT_ID,
     },
     proxy::BaseAssetResponse,
     version_control::{AccountBase, NamespaceInfo, NamespaceResponse},
-    ABSTRACT_EVENT_TYPE,
+    ABSTRACT_EVENT_TYPE, ACCOUNT_FACTORY,
 };
 use abstract_testing::prelude::*;
+use common::CreateAccount;
 use cw_asset::{AssetInfo, AssetInfoBase};
 use cw_orch::prelude::*;
 use speculoos::prelude::*;
@@ -34,6 +43,13 @@ fn instantiate() -> AResult {
         module_factory_address: deployment.module_factory.address()?,
         local_account_sequence: 1,
         ibc_host: Some(deployment.ibc.host.address()?),
+        derive_namespace: false,
+        sequence_offset: 0,
+        sequence_stride: 1,
+        event_namespace: None,
+        bootstrap_governance: None,
+        config_change_subscriber: None,
+        notify_module_factory: false,
     };
 
     assert_that!(&factory_config).is_equal_to(&expected);
@@ -59,6 +75,8 @@ fn create_one_account() -> AResult {
         Some(String::from("account_description")),
         Some(String::from("https://account_link_of_at_least_11_char")),
         None,
+        None,
+        None,
         &[],
     )?;
 
@@ -72,6 +90,13 @@ fn create_one_account() -> AResult {
         module_factory_address: deployment.module_factory.address()?,
         local_account_sequence: 2,
         ibc_host: Some(deployment.ibc.host.address()?),
+        derive_namespace: false,
+        sequence_offset: 0,
+        sequence_stride: 1,
+        event_namespace: None,
+        bootstrap_governance: None,
+        config_change_subscriber: None,
+        notify_module_factory: false,
     };
 
     assert_that!(&factory_config).is_equal_to(&expected);
@@ -115,6 +140,8 @@ fn create_two_account_s() -> AResult {
         Some(String::from("account_description")),
         Some(String::from("https://account_link_of_at_least_11_char")),
         None,
+        None,
+        None,
         &[],
     )?;
     // second account
@@ -129,6 +156,8 @@ fn create_two_account_s() -> AResult {
         Some(String::from("account_description")),
         Some(String::from("https://account_link_of_at_least_11_char")),
         None,
+        None,
+        None,
         &[],
     )?;
 
@@ -148,6 +177,13 @@ fn create_two_account_s() -> AResult {
         // we created two accounts
         local_account_sequence: account_2_id.seq() + 1,
         ibc_host: Some(deployment.ibc.host.address()?),
+        derive_namespace: false,
+        sequence_offset: 0,
+        sequence_stride: 1,
+        event_namespace: None,
+        bootstrap_governance: None,
+        config_change_subscriber: None,
+        notify_module_factory: false,
     };
 
     assert_that!(&factory_config).is_equal_to(&expected);
@@ -195,6 +231,8 @@ fn sender_is_not_admin_monarchy() -> AResult {
         Some(String::from("account_description")),
         Some(String::from("https://account_link_of_at_least_11_char")),
         None,
+        None,
+        None,
         &[],
     )?;
 
@@ -248,6 +286,8 @@ fn sender_is_not_admin_external() -> AResult {
         Some(String::from("account_description")),
         Some(String::from("http://account_link_of_at_least_11_char")),
         None,
+        None,
+        None,
         &[],
     )?;
 
@@ -325,7 +365,9 @@ fn create_one_account_with_namespace() -> AResult {
         None,
         Some(String::from("account_description")),
         Some(String::from("https://account_link_of_at_least_11_char")),
+        None,
         Some(namespace_to_claim.to_string()),
+        None,
         &[],
     )?;
 
@@ -346,6 +388,186 @@ fn create_one_account_with_namespace() -> AResult {
     Ok(())
 }
 
+#[test]
+fn create_account_with_derived_namespace() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain, sender.to_string())?;
+
+    let factory = &deployment.account_factory;
+    let version_control = &deployment.version_control;
+    let create_account = |name: &str| {
+        CreateAccount {
+            name: name.to_owned(),
+            ..CreateAccount::monarchy(&sender)
+        }
+        .create(factory)
+    };
+
+    // Derivation disabled by default
+    create_account("My Account")?;
+    let namespace = version_control.namespace(Namespace::new("my-account")?)?;
+    assert_that!(&namespace).is_equal_to(&NamespaceResponse::Unclaimed {});
+
+    factory.update_config(None, None, Some(true), None, None, None, None)?;
+    assert!(factory.config()?.derive_namespace);
+
+    // Name gets slugified into a namespace
+    let account_creation = create_account(" My  Account!")?;
+    let manager_addr = account_creation.event_attr_value(ABSTRACT_EVENT_TYPE, "manager_address")?;
+    let proxy_addr = account_creation.event_attr_value(ABSTRACT_EVENT_TYPE, "proxy_address")?;
+
+    let namespace = version_control.namespace(Namespace::new("my-account")?)?;
+    assert_that!(&namespace).is_equal_to(&NamespaceResponse::Claimed(NamespaceInfo {
+        account_id: AccountId::local(TEST_ACCOUNT_ID.seq() + 1),
+        account_base: AccountBase {
+            manager: Addr::unchecked(manager_addr),
+            proxy: Addr::unchecked(proxy_addr),
+        },
+    }));
+
+    // Collision with the claimed namespace
+    let err = create_account("my account").unwrap_err();
+    assert!(err.root().to_string().contains(
+        "Namespace \"my-account\" derived from account name \"my account\" is already claimed"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn factory_with_sequence_offset() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+
+    // Second factory that issues the account ids 10, 12, 14, ...
+    let factory = AccountFactory::new("abstract:account-factory-2", chain);
+    factory.set_code_id(deployment.account_factory.code_id()?);
+    factory.instantiate(
+        &account_factory::InstantiateMsg {
+            admin: sender.to_string(),
+            version_control_address: deployment.version_control.addr_str()?,
+            ans_host_address: deployment.ans_host.addr_str()?,
+            module_factory_address: deployment.module_factory.addr_str()?,
+            sequence_offset: Some(10),
+            sequence_stride: Some(2),
+            event_namespace: None,
+            bootstrap_governance: None,
+        },
+        Some(&sender),
+        None,
+    )?;
+    deployment
+        .version_control
+        .update_config(Some(factory.addr_str()?), None, None)?;
+
+    let mut account_ids = vec![];
+    for _ in 0..3 {
+        let account_creation = CreateAccount::monarchy(&sender).create(&factory)?;
+        account_ids.push(account_creation.event_attr_value(ABSTRACT_EVENT_TYPE, "account")?);
+    }
+    assert_that!(account_ids).is_equal_to(
+        [10, 12, 14]
+            .map(|seq| AccountId::local(seq).to_string())
+            .to_vec(),
+    );
+
+    let factory_config = factory.config()?;
+    assert_that!(factory_config.local_account_sequence).is_equal_to(16);
+    assert_that!(factory_config.sequence_offset).is_equal_to(10);
+    assert_that!(factory_config.sequence_stride).is_equal_to(2);
+
+    Ok(())
+}
+
+#[test]
+fn factory_with_event_namespace() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+
+    let factory = AccountFactory::new("abstract:account-factory-2", chain);
+    factory.set_code_id(deployment.account_factory.code_id()?);
+    factory.instantiate(
+        &account_factory::InstantiateMsg {
+            admin: sender.to_string(),
+            version_control_address: deployment.version_control.addr_str()?,
+            ans_host_address: deployment.ans_host.addr_str()?,
+            module_factory_address: deployment.module_factory.addr_str()?,
+            sequence_offset: Some(10),
+            sequence_stride: None,
+            event_namespace: Some("tenant".to_owned()),
+            bootstrap_governance: None,
+        },
+        Some(&sender),
+        None,
+    )?;
+    deployment
+        .version_control
+        .update_config(Some(factory.addr_str()?), None, None)?;
+
+    let account_creation = CreateAccount::monarchy(&sender).create(&factory)?;
+
+    // Factory events are prefixed with the event namespace
+    let action = account_creation.event_attr_value("wasm-tenant-abstract", "action")?;
+    assert_that!(action).is_equal_to("create_account".to_owned());
+    let manager = account_creation.event_attr_value("wasm-tenant-abstract", "manager_address");
+    assert_that!(manager).is_ok();
+    let unprefixed_events = account_creation
+        .events()
+        .into_iter()
+        .filter(|event| {
+            event.ty == ABSTRACT_EVENT_TYPE
+                && event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "contract" && attr.value == ACCOUNT_FACTORY)
+        })
+        .count();
+    assert_that!(unprefixed_events).is_equal_to(0);
+
+    Ok(())
+}
+
+#[test]
+fn create_account_with_proxy_admin() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+
+    let factory = &deployment.account_factory;
+
+    for proxy_admin in [
+        None,
+        Some(ManagerOrGovernance::Manager),
+        Some(ManagerOrGovernance::Governance),
+    ] {
+        let account_creation = CreateAccount {
+            proxy_admin: proxy_admin.clone(),
+            ..CreateAccount::monarchy(&sender)
+        }
+        .create(factory)?;
+
+        let manager = account_creation.event_attr_value(ABSTRACT_EVENT_TYPE, "manager_address")?;
+        let proxy = account_creation.event_attr_value(ABSTRACT_EVENT_TYPE, "proxy_address")?;
+        let admin = chain
+            .app
+            .borrow()
+            .wrap()
+            .query_wasm_contract_info(proxy)?
+            .admin;
+
+        let expected_admin = match proxy_admin.unwrap_or_default() {
+            ManagerOrGovernance::Manager => manager,
+            ManagerOrGovernance::Governance => sender.to_string(),
+        };
+        assert_that!(admin).is_equal_to(Some(expected_admin));
+    }
+
+    Ok(())
+}
+
 #[test]
 fn create_one_account_with_namespace_fee() -> AResult {
     let chain = MockBech32::new("mock");
@@ -353,3 +575,364 @@ fn create_one_account_with_namespace_fee() -> AResult {
     Abstract::deploy_on(chain.clone(), sender.to_string())?;
     abstract_integration_tests::account_factory::create_one_account_with_namespace_fee(chain)
 }
+
+/// Deploys a new version control and account factory that requires the given bootstrap governance
+fn deploy_bootstrap_factory(
+    chain: &MockBech32,
+    deployment: &Abstract<MockBech32>,
+    bootstrap_governance: UncheckedBootstrapGovernance,
+) -> anyhow::Result<(VersionControl<MockBech32>, AccountFactory<MockBech32>)> {
+    let sender = chain.sender();
+    // The bootstrap account is already registered on the deployment's version control
+    let version_control = VersionControl::new("abstract:version-control-2", chain.clone());
+    version_control.set_code_id(deployment.version_control.code_id()?);
+    version_control.instantiate(
+        &abstract_std::version_control::InstantiateMsg {
+            admin: sender.to_string(),
+            security_disabled: Some(true),
+            namespace_registration_fee: None,
+        },
+        Some(&sender),
+        None,
+    )?;
+    version_control.register_base(&deployment.account)?;
+    version_control.approve_any_abstract_modules()?;
+
+    let factory = AccountFactory::new("abstract:account-factory-2", chain.clone());
+    factory.set_code_id(deployment.account_factory.code_id()?);
+    factory.instantiate(
+        &account_factory::InstantiateMsg {
+            admin: sender.to_string(),
+            version_control_address: version_control.addr_str()?,
+            ans_host_address: deployment.ans_host.addr_str()?,
+            module_factory_address: deployment.module_factory.addr_str()?,
+            sequence_offset: None,
+            sequence_stride: None,
+            event_namespace: None,
+            bootstrap_governance: Some(bootstrap_governance),
+        },
+        Some(&sender),
+        None,
+    )?;
+    version_control.update_config(Some(factory.addr_str()?), None, None)?;
+    Ok((version_control, factory))
+}
+
+#[test]
+fn bootstrap_account_governance() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+    let (version_control, factory) = deploy_bootstrap_factory(
+        &chain,
+        &deployment,
+        UncheckedBootstrapGovernance::FactoryOwner,
+    )?;
+
+    // Bootstrap account not owned by the factory owner
+    let err = CreateAccount::monarchy(&chain.addr_make("other"))
+        .create(&factory)
+        .unwrap_err();
+    assert_that!(err.root().to_string())
+        .contains("Governance of the bootstrap account doesn't match");
+
+    CreateAccount::monarchy(&sender).create(&factory)?;
+    let bootstrap_account = version_control.account_base(ABSTRACT_ACCOUNT_ID);
+    assert_that!(bootstrap_account).is_ok();
+
+    // Other accounts can have any governance
+    CreateAccount::monarchy(&chain.addr_make("other")).create(&factory)?;
+
+    Ok(())
+}
+
+#[test]
+fn bootstrap_account_external_governance() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+    let governance = chain.addr_make("governance");
+
+    // The governance address is validated
+    let res = deploy_bootstrap_factory(
+        &chain,
+        &deployment,
+        UncheckedBootstrapGovernance::External {
+            governance_address: "invalid".to_owned(),
+        },
+    );
+    assert_that!(res).is_err();
+
+    let (version_control, factory) = deploy_bootstrap_factory(
+        &chain,
+        &deployment,
+        UncheckedBootstrapGovernance::External {
+            governance_address: governance.to_string(),
+        },
+    )?;
+    assert_that!(factory.config()?.bootstrap_governance).is_equal_to(Some(
+        BootstrapGovernance::External {
+            governance_address: governance.clone(),
+        },
+    ));
+
+    let create_bootstrap_account = |governance_address: &Addr| {
+        CreateAccount::new(GovernanceDetails::External {
+            governance_address: governance_address.to_string(),
+            governance_type: "dao-dao".to_owned(),
+        })
+        .create(&factory)
+    };
+
+    // Bootstrap account with another external governance
+    let err = create_bootstrap_account(&chain.addr_make("other")).unwrap_err();
+    assert_that!(err.root().to_string())
+        .contains("Governance of the bootstrap account doesn't match");
+
+    create_bootstrap_account(&governance)?;
+    let bootstrap_account = version_control.account_base(ABSTRACT_ACCOUNT_ID);
+    assert_that!(bootstrap_account).is_ok();
+
+    Ok(())
+}
+
+#[test]
+fn create_account_with_linked_contracts() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+
+    let factory = &deployment.account_factory;
+    let vault = chain.addr_make("vault");
+
+    let account_creation = CreateAccount {
+        linked_contracts: Some(vec![("vault".to_owned(), vault.to_string())]),
+        ..CreateAccount::monarchy(&sender)
+    }
+    .create(factory)?;
+    let linked_contract =
+        account_creation.event_attr_value(ABSTRACT_EVENT_TYPE, "linked_contract")?;
+    assert_that!(linked_contract).is_equal_to(format!("vault:{vault}"));
+    let linked_contracts = factory.linked_contracts(TEST_ACCOUNT_ID)?;
+    assert_that!(linked_contracts.linked_contracts).is_equal_to(vec![("vault".to_owned(), vault)]);
+
+    // Accounts without links have no linked contracts
+    CreateAccount::monarchy(&sender).create(factory)?;
+    let linked_contracts = factory.linked_contracts(AccountId::local(2))?;
+    assert_that!(linked_contracts.linked_contracts).is_empty();
+
+    // Linked addresses are validated
+    let err = CreateAccount {
+        linked_contracts: Some(vec![("vault".to_owned(), "not a valid address".to_owned())]),
+        ..CreateAccount::monarchy(&sender)
+    }
+    .create(factory);
+    assert_that!(err).is_err();
+
+    // Labels must be non-empty, unique and can't contain the attribute separator
+    for linked_contracts in [
+        vec![(String::new(), vault.to_string())],
+        vec![("vault:v2".to_owned(), vault.to_string())],
+        vec![
+            ("vault".to_owned(), vault.to_string()),
+            ("vault".to_owned(), sender.to_string()),
+        ],
+    ] {
+        let err = CreateAccount {
+            linked_contracts: Some(linked_contracts),
+            ..CreateAccount::monarchy(&sender)
+        }
+        .create(factory)
+        .unwrap_err();
+        assert_that!(err.root().to_string()).contains("is invalid");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn blocked_owners() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+
+    let factory = &deployment.account_factory;
+    let blocked = chain.addr_make("blocked");
+    factory.update_blocked_owners(vec![blocked.to_string()], vec![])?;
+    assert_that!(factory.blocked_owners(None, None)?.owners).is_equal_to(vec![blocked.clone()]);
+
+    let create_account = |governance| CreateAccount::new(governance).create(factory);
+
+    // Blocked addresses can't own accounts through any governance
+    for governance in [
+        GovernanceDetails::Monarchy {
+            monarch: blocked.to_string(),
+        },
+        GovernanceDetails::External {
+            governance_address: blocked.to_string(),
+            governance_type: "some-gov-type".to_string(),
+        },
+    ] {
+        let err = create_account(governance).unwrap_err();
+        assert_that!(err.root().to_string())
+            .contains(format!("Owner {blocked} is blocked from owning accounts"));
+    }
+
+    // Other addresses can
+    create_account(GovernanceDetails::Monarchy {
+        monarch: sender.to_string(),
+    })?;
+    let account = AbstractAccount::new(&deployment, TEST_ACCOUNT_ID);
+
+    // Sub-accounts of a blocked account are refused
+    factory.update_blocked_owners(vec![account.proxy.addr_str()?], vec![])?;
+    let res = account.manager.create_sub_account(
+        vec![],
+        "sub_account".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        &[],
+    );
+    assert_that!(res).is_err();
+
+    // Unblocked owners can own accounts again
+    factory.update_blocked_owners(vec![], vec![blocked.to_string(), account.proxy.addr_str()?])?;
+    assert_that!(factory.blocked_owners(None, None)?.owners).is_empty();
+    create_account(GovernanceDetails::Monarchy {
+        monarch: blocked.to_string(),
+    })?;
+    account.manager.create_sub_account(
+        vec![],
+        "sub_account".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        &[],
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn min_module_version() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain.clone(), sender.to_string())?;
+
+    let factory = &deployment.account_factory;
+    let create_account = |install_modules: Vec<ModuleInstallConfig>, namespace: Option<String>| {
+        CreateAccount {
+            install_modules,
+            namespace,
+            ..CreateAccount::monarchy(&sender)
+        }
+        .create(factory)
+    };
+    let adapter_version = |version: ModuleVersion| {
+        vec![ModuleInstallConfig::new(
+            ModuleInfo::from_id(adapter_1::MOCK_ADAPTER_ID, version).unwrap(),
+            None,
+        )]
+    };
+    create_account(vec![], Some(TEST_NAMESPACE.to_owned()))?;
+    deploy_modules(&chain);
+
+    // A global minimum applies to all modules
+    factory.update_min_module_version(None, Some("1.5.0".to_owned()))?;
+    assert_that!(factory.min_module_version(None)?.version).is_equal_to(Some("1.5.0".to_owned()));
+
+    // Below the minimum
+    let err = create_account(adapter_version(V1.into()), None).unwrap_err();
+    assert_that!(err.root().to_string()).contains(&format!(
+        "Module {} version {V1} is older than the minimum version 1.5.0",
+        adapter_1::MOCK_ADAPTER_ID
+    ));
+
+    // Above the minimum, also when installing the latest version
+    create_account(adapter_version(V2.into()), None)?;
+    create_account(adapter_version(ModuleVersion::Latest), None)?;
+
+    // The minimum of a namespace takes precedence over the global minimum
+    factory.update_min_module_version(Some(TEST_NAMESPACE.to_owned()), Some(V2.to_owned()))?;
+    assert_that!(
+        factory
+            .min_module_version(Some(TEST_NAMESPACE.to_owned()))?
+            .version
+    )
+    .is_equal_to(Some(V2.to_owned()));
+
+    // At the minimum
+    create_account(adapter_version(V2.into()), None)?;
+    let err = create_account(adapter_version(V1.into()), None).unwrap_err();
+    assert_that!(err.root().to_string()).contains(&format!(
+        "Module {} version {V1} is older than the minimum version {V2}",
+        adapter_1::MOCK_ADAPTER_ID
+    ));
+
+    // Removing the minimums allows older versions again
+    factory.update_min_module_version(Some(TEST_NAMESPACE.to_owned()), None)?;
+    factory.update_min_module_version(None, None)?;
+    assert_that!(factory.min_module_version(None)?.version).is_none();
+    create_account(adapter_version(V1.into()), None)?;
+
+    // Only the owner can set a minimum
+    let res = factory
+        .call_as(&chain.addr_make("not_owner"))
+        .update_min_module_version(None, Some(V2.to_owned()));
+    assert_that!(res).is_err();
+
+    Ok(())
+}
+
+#[test]
+fn notify_module_factory() -> AResult {
+    let chain = MockBech32::new("mock");
+    let sender = chain.sender();
+    let deployment = Abstract::deploy_on(chain, sender.to_string())?;
+
+    let factory = &deployment.account_factory;
+    let create_account = || CreateAccount::monarchy(&sender).create(factory);
+
+    // The module factory isn't notified by default
+    let res = create_account()?;
+    assert_that!(res.event_attr_values(ABSTRACT_EVENT_TYPE, "action"))
+        .does_not_contain("account_created".to_owned());
+
+    factory.update_config(None, None, None, None, None, Some(true), None)?;
+    assert!(factory.config()?.notify_module_factory);
+
+    let res = create_account()?;
+    assert_that!(res.event_attr_values(ABSTRACT_EVENT_TYPE, "action"))
+        .contains("account_created".to_owned());
+    let proxy = res.event_attr_value(ABSTRACT_EVENT_TYPE, "proxy_address")?;
+    assert_that!(res.event_attr_values(ABSTRACT_EVENT_TYPE, "proxy_address"))
+        .is_equal_to(vec![proxy.clone(), proxy]);
+
+    // Only the account factory can notify the module factory
+    let account = AbstractAccount::new(&deployment, TEST_ACCOUNT_ID);
+    let err = deployment
+        .module_factory
+        .account_created(
+            AccountBase {
+                manager: account.manager.address()?,
+                proxy: account.proxy.address()?,
+            },
+            TEST_ACCOUNT_ID,
+        )
+        .unwrap_err();
+    assert_that!(err.root().to_string())
+        .contains("Only the account factory can notify created accounts");
+
+    // Disabling the notification stops it
+    factory.update_config(None, None, None, None, None, Some(false), None)?;
+    let res = create_account()?;
+    assert_that!(res.event_attr_values(ABSTRACT_EVENT_TYPE, "action"))
+        .does_not_contain("account_created".to_owned());
+
+    Ok(())
+}
@@ -60,6 +60,8 @@ pub fn receive_register(
             base_asset,
             install_modules,
             namespace,
+            proxy_admin: None,
+            linked_contracts: None,
         },
         vec![],
     )?;
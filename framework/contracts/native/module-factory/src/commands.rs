@@ -3,15 +3,16 @@ use abstract_sdk::{
         module_factory::FactoryModuleInstallConfig,
         objects::{
             module::ModuleInfo, module_reference::ModuleReference,
-            version_control::VersionControlContract,
+            version_control::VersionControlContract, AccountId,
         },
+        version_control::{self, AccountBase},
     },
     *,
 };
 use abstract_std::objects::module;
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, BankMsg, Binary, CanonicalAddr, Coin, Coins, CosmosMsg, Deps,
-    DepsMut, Env, MessageInfo, StdResult, WasmMsg,
+    ensure, from_json, to_json_binary, Addr, BankMsg, Binary, CanonicalAddr, Coin, Coins,
+    CosmosMsg, Deps, DepsMut, Env, MessageInfo, StdResult, WasmMsg,
 };
 use serde_cw_value::Value;
 
@@ -261,6 +262,31 @@ pub fn update_factory_binaries(
     Ok(ModuleFactoryResponse::action("update_factory_binaries"))
 }
 
+/// Acknowledges an account created by the account factory registered on version control
+pub fn execute_account_created(
+    deps: DepsMut,
+    info: MessageInfo,
+    account_id: AccountId,
+    account_base: AccountBase,
+) -> ModuleFactoryResult {
+    let config = CONFIG.load(deps.storage)?;
+    let version_control_config =
+        version_control::state::CONFIG.query(&deps.querier, config.version_control_address)?;
+    ensure!(
+        version_control_config.account_factory_address == Some(info.sender),
+        ModuleFactoryError::NotAccountFactory {}
+    );
+
+    Ok(ModuleFactoryResponse::new(
+        "account_created",
+        vec![
+            ("account", account_id.to_string()),
+            ("manager_address", account_base.manager.into_string()),
+            ("proxy_address", account_base.proxy.into_string()),
+        ],
+    ))
+}
+
 #[cfg(test)]
 mod test {
     use abstract_std::module_factory::ExecuteMsg;
@@ -62,6 +62,10 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> M
         ExecuteMsg::UpdateFactoryBinaryMsgs { to_add, to_remove } => {
             commands::update_factory_binaries(deps, info, to_add, to_remove)
         }
+        ExecuteMsg::AccountCreated {
+            account_id,
+            account_base,
+        } => commands::execute_account_created(deps, info, account_id, account_base),
         ExecuteMsg::UpdateOwnership(action) => {
             abstract_sdk::execute_update_ownership!(ModuleFactoryResponse, deps, env, info, action)
         }
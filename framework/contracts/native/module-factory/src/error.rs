@@ -31,6 +31,9 @@ pub enum ModuleFactoryError {
     #[error("Calling contract is not a registered Account Manager")]
     UnknownCaller(),
 
+    #[error("Only the account factory can notify created accounts")]
+    NotAccountFactory {},
+
     #[error("Reply ID does not match any known Reply ID")]
     UnexpectedReply(),
 
@@ -35,7 +35,9 @@ pub fn create_one_account_with_namespace_fee<T: MutCwEnv>(mut chain: T) -> AResu
         None,
         Some(String::from("account_description")),
         Some(String::from("https://account_link_of_at_least_11_char")),
+        None,
         Some(namespace_to_claim.to_string()),
+        None,
         // Account creation fee not covered
         &[],
     );
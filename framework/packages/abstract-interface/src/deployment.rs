@@ -106,10 +106,13 @@ impl<Chain: CwEnv> Deploy<Chain> for Abstract<Chain> {
         deployment
             .account_factory
             .update_config(
+                None,
+                None,
                 None,
                 Some(deployment.ibc.host.address().unwrap().to_string()),
                 None,
                 None,
+                None,
             )
             .unwrap();
 
@@ -227,6 +230,10 @@ impl<Chain: CwEnv> Abstract<Chain> {
                 version_control_address: self.version_control.address()?.into_string(),
                 ans_host_address: self.ans_host.address()?.into_string(),
                 module_factory_address: self.module_factory.address()?.into_string(),
+                sequence_offset: None,
+                sequence_stride: None,
+                event_namespace: None,
+                bootstrap_governance: None,
             },
             Some(&admin),
             None,
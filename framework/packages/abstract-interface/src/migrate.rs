@@ -15,7 +15,7 @@ impl<T: CwEnv> Abstract<T> {
         // start with factories
         let account_factory = self
             .account_factory
-            .upload_and_migrate_if_needed(&account_factory::MigrateMsg {})?;
+            .upload_and_migrate_if_needed(&account_factory::MigrateMsg::default())?;
         let module_factory = self
             .module_factory
             .upload_and_migrate_if_needed(&module_factory::MigrateMsg {})?;
@@ -55,7 +55,7 @@ impl<T: CwEnv> Abstract<T> {
         {
             let migration_result = self
                 .account_factory
-                .upload_and_migrate_if_needed(&account_factory::MigrateMsg {})?;
+                .upload_and_migrate_if_needed(&account_factory::MigrateMsg::default())?;
             if migration_result.is_some() {
                 has_migrated = true;
             }
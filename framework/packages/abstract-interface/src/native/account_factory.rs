@@ -74,6 +74,8 @@ impl<Chain: CwEnv> AccountFactory<Chain> {
                 namespace,
                 base_asset,
                 install_modules,
+                proxy_admin: None,
+                linked_contracts: None,
             },
             funds,
         )?;
@@ -9,13 +9,16 @@
 //!
 pub mod state {
     use cosmwasm_std::Addr;
-    use cw_storage_plus::Item;
+    use cw_storage_plus::{Item, Map};
+    use semver::Version;
     use serde::{Deserialize, Serialize};
 
+    use super::BootstrapGovernance;
     use crate::{
         objects::{
             account::{AccountId, AccountSequence},
             module::Module,
+            namespace::Namespace,
         },
         version_control::AccountBase,
     };
@@ -27,6 +30,31 @@ pub mod state {
         pub ans_host_contract: Addr,
         pub module_factory_address: Addr,
         pub ibc_host: Option<Addr>,
+        /// Derive a namespace from the account name when none is provided on account creation.
+        #[serde(default)]
+        pub derive_namespace: bool,
+        /// First local account sequence issued by this factory.
+        #[serde(default)]
+        pub sequence_offset: AccountSequence,
+        /// Step between the local account sequences issued by this factory.
+        #[serde(default = "default_sequence_stride")]
+        pub sequence_stride: AccountSequence,
+        /// Prefix of the type of the events emitted by this factory.
+        #[serde(default)]
+        pub event_namespace: Option<String>,
+        /// Governance the bootstrap account is required to have.
+        #[serde(default)]
+        pub bootstrap_governance: Option<BootstrapGovernance>,
+        /// Contract notified with [`super::ConfigChangeSubscriberMsg::ConfigChanged`] when the config changes.
+        #[serde(default)]
+        pub config_change_subscriber: Option<Addr>,
+        /// Notify the module factory with [`crate::module_factory::ExecuteMsg::AccountCreated`] of created accounts.
+        #[serde(default)]
+        pub notify_module_factory: bool,
+    }
+
+    fn default_sequence_stride() -> AccountSequence {
+        1
     }
 
     /// Account Factory context for post-[`crate::manager`] [`crate::proxy`] creation
@@ -41,16 +69,28 @@ pub mod state {
     pub const CONFIG: Item<Config> = Item::new("cfg");
     pub const CONTEXT: Item<Context> = Item::new("contxt");
     pub const LOCAL_ACCOUNT_SEQUENCE: Item<AccountSequence> = Item::new("acseq");
+    /// Contracts linked to an account at its creation, as (label, address) pairs
+    pub const LINKED_CONTRACTS: Map<&AccountId, Vec<(String, Addr)>> = Map::new("lnkd");
+    /// Addresses that can't own new accounts
+    pub const BLOCKED_OWNERS: Map<&Addr, ()> = Map::new("blkd");
+    /// Minimum version of all modules installed on new accounts
+    pub const GLOBAL_MIN_MODULE_VERSION: Item<Version> = Item::new("gminv");
+    /// Minimum version of the modules of a namespace installed on new accounts, takes precedence over the global minimum
+    pub const MIN_MODULE_VERSIONS: Map<&Namespace, Version> = Map::new("minv");
 }
 
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Api, StdResult};
+use cw_address_like::AddressLike;
+use cw_clearable::Clearable;
 
 use crate::{
     manager::ModuleInstallConfig,
+    module_factory::SimulateInstallModulesResponse,
     objects::{
         account::{AccountId, AccountSequence, AccountTrace},
         gov_type::GovernanceDetails,
+        module::ModuleInfo,
         AssetEntry,
     },
 };
@@ -66,6 +106,40 @@ pub struct InstantiateMsg {
     pub ans_host_address: String,
     /// AnsHosts of module factory. Used for instantiating manager.
     pub module_factory_address: String,
+    /// First local account sequence issued by this factory, defaults to 0.
+    pub sequence_offset: Option<AccountSequence>,
+    /// Step between the local account sequences issued by this factory, defaults to 1.
+    /// Factories sharing a version control can use distinct offsets with the same stride to issue disjoint account ids.
+    pub sequence_stride: Option<AccountSequence>,
+    /// Prefix the type of the emitted events with this namespace, i.e. `wasm-<event_namespace>-abstract`.
+    /// Allows indexers to distinguish the events of multiple deployments on the same chain.
+    pub event_namespace: Option<String>,
+    /// Governance the bootstrap account is required to have. Any governance is accepted if `None`.
+    pub bootstrap_governance: Option<UncheckedBootstrapGovernance>,
+}
+
+/// Governance required for the bootstrap account, the account with the reserved [`crate::objects::ABSTRACT_ACCOUNT_ID`].
+#[cosmwasm_schema::cw_serde]
+pub enum BootstrapGovernanceBase<T: AddressLike> {
+    /// A monarchy of the factory owner.
+    FactoryOwner,
+    /// The given external governance contract.
+    External { governance_address: T },
+}
+
+pub type UncheckedBootstrapGovernance = BootstrapGovernanceBase<String>;
+pub type BootstrapGovernance = BootstrapGovernanceBase<Addr>;
+
+impl UncheckedBootstrapGovernance {
+    /// Validate the address of the external governance
+    pub fn check(self, api: &dyn Api) -> StdResult<BootstrapGovernance> {
+        Ok(match self {
+            Self::FactoryOwner => BootstrapGovernance::FactoryOwner,
+            Self::External { governance_address } => BootstrapGovernance::External {
+                governance_address: api.addr_validate(&governance_address)?,
+            },
+        })
+    }
 }
 
 /// Account Factory execute messages
@@ -83,6 +157,23 @@ pub enum ExecuteMsg {
         module_factory_address: Option<String>,
         // New ibc host contract
         ibc_host: Option<String>,
+        /// Enable or disable namespace derivation from the account name
+        derive_namespace: Option<bool>,
+        /// Contract to notify when the config changes
+        config_change_subscriber: Option<Clearable<String>>,
+        /// Enable or disable notifying the module factory of created accounts
+        notify_module_factory: Option<bool>,
+    },
+    /// Update the addresses that are blocked from owning new accounts
+    UpdateBlockedOwners {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
+    /// Set or remove the minimum version of the modules installed on new accounts.
+    /// Applies to the modules of `namespace`, or to all modules if no namespace is given.
+    UpdateMinModuleVersion {
+        namespace: Option<String>,
+        version: Option<String>,
     },
     /// Creates the core contracts and sets the permissions.
     /// [`crate::manager`] and [`crate::proxy`]
@@ -98,6 +189,8 @@ pub enum ExecuteMsg {
         description: Option<String>,
         // Account link
         link: Option<String>,
+        /// External contracts linked to the account, as (label, address) pairs.
+        linked_contracts: Option<Vec<(String, String)>>,
         /// Indicates the AccountId for the new account.
         ///
         /// If `None`, will create a new local account without asserting account-id.
@@ -105,13 +198,31 @@ pub enum ExecuteMsg {
         /// When [`AccountTrace::Local`]: Signals the expected Account Id. The tx will error if this does not match the account-id at runtime. Useful for instantiate2 address prediction. \
         /// When [`AccountTrace::Remote`]: Account id on the remote chain.
         account_id: Option<AccountId>,
-        // optionally specify a namespace for the account
+        /// Optionally specify a namespace for the account.
+        ///
+        /// If `None` and namespace derivation is enabled, the namespace is derived from the account `name`.
         namespace: Option<String>,
         // Provide list of module to install after account creation
         install_modules: Vec<ModuleInstallConfig>,
+        /// Admin of the proxy contract, defaults to [`ManagerOrGovernance::Manager`].
+        /// See [`ManagerOrGovernance::Governance`] for what the manager loses otherwise.
+        proxy_admin: Option<ManagerOrGovernance>,
     },
 }
 
+/// Who is set as the (migration) admin of the account's proxy contract.
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub enum ManagerOrGovernance {
+    /// The account's manager, which allows the account to upgrade its proxy.
+    #[default]
+    Manager,
+    /// The owner of the account's governance, giving it direct upgrade control over the proxy.
+    /// The manager can then no longer migrate the proxy: upgrading the proxy through the manager's
+    /// `Upgrade` fails and the proxy has to be migrated by the owner directly.
+    Governance,
+}
+
 /// Account Factory query messages
 #[cw_ownable::cw_ownable_query]
 #[cosmwasm_schema::cw_serde]
@@ -120,6 +231,26 @@ pub enum QueryMsg {
     /// Returns [`ConfigResponse`]
     #[returns(ConfigResponse)]
     Config {},
+    /// Simulates the installation of the given modules on a new account.
+    /// Proxies the query to the module factory.
+    /// Returns [`SimulateInstallModulesResponse`]
+    #[returns(SimulateInstallModulesResponse)]
+    SimulateInstallModules { modules: Vec<ModuleInfo> },
+    /// Returns the contracts linked to an account at its creation.
+    /// Returns [`LinkedContractsResponse`]
+    #[returns(LinkedContractsResponse)]
+    LinkedContracts { account_id: AccountId },
+    /// Returns the addresses that are blocked from owning new accounts.
+    /// Returns [`BlockedOwnersResponse`]
+    #[returns(BlockedOwnersResponse)]
+    BlockedOwners {
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+    /// Returns the minimum version of the modules of `namespace`, or of all modules if no namespace is given.
+    /// Returns [`MinModuleVersionResponse`]
+    #[returns(MinModuleVersionResponse)]
+    MinModuleVersion { namespace: Option<String> },
 }
 
 /// Account Factory config response
@@ -130,6 +261,40 @@ pub struct ConfigResponse {
     pub module_factory_address: Addr,
     pub ibc_host: Option<Addr>,
     pub local_account_sequence: AccountSequence,
+    pub derive_namespace: bool,
+    pub sequence_offset: AccountSequence,
+    pub sequence_stride: AccountSequence,
+    pub event_namespace: Option<String>,
+    pub bootstrap_governance: Option<BootstrapGovernance>,
+    pub config_change_subscriber: Option<Addr>,
+    pub notify_module_factory: bool,
+}
+
+/// Message sent to the config change subscriber of the account factory
+#[cosmwasm_schema::cw_serde]
+pub enum ConfigChangeSubscriberMsg {
+    /// The config of the account factory changed
+    ConfigChanged {},
+}
+
+/// Linked contracts response
+#[cosmwasm_schema::cw_serde]
+pub struct LinkedContractsResponse {
+    /// (label, address) pairs of the linked contracts
+    pub linked_contracts: Vec<(String, Addr)>,
+}
+
+/// Blocked owners response
+#[cosmwasm_schema::cw_serde]
+pub struct BlockedOwnersResponse {
+    pub owners: Vec<Addr>,
+}
+
+/// Minimum module version response
+#[cosmwasm_schema::cw_serde]
+pub struct MinModuleVersionResponse {
+    /// Minimum version, if any
+    pub version: Option<String>,
 }
 
 /// Sequence numbers for each origin.
@@ -145,4 +310,10 @@ pub struct SequenceResponse {
 
 /// Account Factory migrate messages
 #[cosmwasm_schema::cw_serde]
-pub struct MigrateMsg {}
+#[derive(Default)]
+pub struct MigrateMsg {
+    /// New first local account sequence issued by this factory
+    pub sequence_offset: Option<AccountSequence>,
+    /// New step between the local account sequences issued by this factory
+    pub sequence_stride: Option<AccountSequence>,
+}
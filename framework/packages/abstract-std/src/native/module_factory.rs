@@ -26,7 +26,10 @@ pub mod state {
 use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::{Addr, Binary, Coin};
 
-use crate::objects::module::ModuleInfo;
+use crate::{
+    objects::{module::ModuleInfo, AccountId},
+    version_control::AccountBase,
+};
 
 #[cosmwasm_schema::cw_serde]
 pub struct InstantiateMsg {
@@ -56,6 +59,11 @@ pub enum ExecuteMsg {
         to_add: Vec<(ModuleInfo, Binary)>,
         to_remove: Vec<ModuleInfo>,
     },
+    /// Notification of the account factory that an account was created
+    AccountCreated {
+        account_id: AccountId,
+        account_base: AccountBase,
+    },
 }
 
 /// Module info, init message and salt
@@ -6,7 +6,7 @@ use abstract_std::objects::{
     ans_host::AnsHostError, AnsAsset, AnsEntryConvertor, AssetEntry, DexAssetPairing, PoolAddress,
     PoolReference,
 };
-use cosmwasm_std::{Decimal, StdError};
+use cosmwasm_std::{Decimal, StdError, Uint128};
 use cw_asset::Asset;
 
 use crate::{msg::DexName, raw_action::DexRawAction};
@@ -46,6 +46,14 @@ pub enum DexAnsAction {
         max_spread: Option<Decimal>,
         /// The belief price when submitting the transaction.
         belief_price: Option<Decimal>,
+        /// The minimum amount of ask asset to receive. Can't be combined with `max_spread`
+        /// or `belief_price`.
+        min_receive: Option<Uint128>,
+        /// Ignore the slippage parameters of the swap. Only honored for keepers of the dex adapter.
+        #[serde(default)]
+        bypass_slippage: bool,
+        /// Address that receives the output of the swap, the account if unset.
+        output_recipient: Option<String>,
     },
 }
 /// Structure created to be able to resolve an action using ANS
@@ -147,6 +155,9 @@ impl Resolve for WholeDexAction {
                 mut ask_asset,
                 max_spread,
                 belief_price,
+                min_receive,
+                bypass_slippage,
+                output_recipient,
             } => {
                 let AnsAsset {
                     name: mut offer_asset,
@@ -172,6 +183,9 @@ impl Resolve for WholeDexAction {
                     ask_asset: ask_asset_info.into(),
                     max_spread,
                     belief_price,
+                    min_receive,
+                    bypass_slippage,
+                    output_recipient,
                 })
             }
         }
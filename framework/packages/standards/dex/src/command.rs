@@ -54,6 +54,20 @@ pub trait DexCommand: Identify {
         max_spread: Option<Decimal>,
     ) -> Result<Vec<CosmosMsg>, DexError>;
 
+    /// Execute a swap through the given router contract of the DEX instead of directly on a pool
+    #[allow(clippy::too_many_arguments)]
+    fn swap_via_router(
+        &self,
+        _deps: Deps,
+        _router: Addr,
+        _offer_asset: Asset,
+        _ask_asset: AssetInfo,
+        _belief_price: Option<Decimal>,
+        _max_spread: Option<Decimal>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
     /// Provides liquidity on the the DEX
     fn provide_liquidity(
         &self,
@@ -89,6 +103,17 @@ pub trait DexCommand: Identify {
         ask_asset: AssetInfo,
     ) -> Result<(Return, Spread, Fee, FeeOnInput), DexError>;
 
+    /// Query the reserves of each of the given assets in the pool, in the given order.
+    /// Not implemented for dexes whose pools don't report their reserves.
+    fn pool_liquidity(
+        &self,
+        _deps: Deps,
+        _pool_id: PoolAddress,
+        _assets: Vec<AssetInfo>,
+    ) -> Result<Vec<Asset>, DexError> {
+        Err(DexError::NotImplemented(self.name().to_owned()))
+    }
+
     /// Fetch data for execute methods
     fn fetch_data(
         &mut self,
@@ -1,10 +1,10 @@
 use abstract_adapter::AdapterError;
 use abstract_sdk::AbstractSdkError;
 use abstract_std::{
-    objects::{ans_host::AnsHostError, DexAssetPairing},
+    objects::{ans_host::AnsHostError, AssetEntry, DexAssetPairing},
     AbstractError,
 };
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use cw_asset::AssetError;
 use thiserror::Error;
 
@@ -28,6 +28,9 @@ pub enum DexError {
     #[error("{0}")]
     AnsHostError(#[from] AnsHostError),
 
+    #[error("Failed to resolve on the primary ANS host: {primary}, and on the secondary ANS host: {secondary}")]
+    AnsFallbackFailed { primary: String, secondary: String },
+
     #[error("DEX {0} is not a known dex on this network.")]
     UnknownDex(String),
 
@@ -55,6 +58,9 @@ pub enum DexError {
     #[error("Not implemented for dex {0}")]
     NotImplemented(String),
 
+    #[error("`min_receive` can't be combined with `max_spread` or `belief_price`")]
+    ConflictingSlippageParams {},
+
     #[error("Maximum spread {0} exceeded for dex {1}")]
     MaxSlippageAssertion(String, String),
 
@@ -72,4 +78,90 @@ pub enum DexError {
 
     #[error("Only account of abstract namespace can update configuration")]
     Unauthorized {},
+
+    #[error(
+        "Pool {pool} holds {liquidity} which is below the minimum liquidity of {min_liquidity}"
+    )]
+    InsufficientPoolLiquidity {
+        pool: String,
+        liquidity: String,
+        min_liquidity: Uint128,
+    },
+
+    #[error("Maximum pool share must be between 0 (exclusive) and 1")]
+    InvalidMaxPoolShare {},
+
+    #[error(
+        "Swap offers {offer_amount} which exceeds the maximum of {max_offer_amount} for the pool"
+    )]
+    SwapTooLargeForPool {
+        offer_amount: Uint128,
+        max_offer_amount: Uint128,
+    },
+
+    #[error("Volume cap must have a non-zero maximum volume and window")]
+    InvalidVolumeCap {},
+
+    #[error("Swapping {offer_amount} {asset} exceeds the volume cap of {max_volume}, {volume} was already swapped in this window")]
+    GlobalVolumeCapExceeded {
+        asset: String,
+        offer_amount: Uint128,
+        volume: Uint128,
+        max_volume: Uint128,
+    },
+
+    #[error("Asset {asset} is blocked")]
+    AssetBlocked { asset: String },
+
+    #[error("Asset {asset} is not allowed")]
+    AssetNotAllowed { asset: String },
+
+    #[error("Only keepers can refresh cached quotes")]
+    NotKeeper {},
+
+    #[error("Another swap is in progress")]
+    SwapInProgress {},
+
+    #[error("Quote cache is disabled")]
+    QuoteCacheDisabled {},
+
+    #[error("Quote cache already holds the maximum of {max} quotes")]
+    QuoteCacheFull { max: usize },
+
+    #[error("Swap didn't return the expected asset {expected}")]
+    UnexpectedOutputAsset { expected: String },
+
+    #[error("Swap offers {offer_amount} which exceeds the large swap threshold of {threshold}, it has to be scheduled")]
+    SwapMustBeScheduled {
+        offer_amount: Uint128,
+        threshold: Uint128,
+    },
+
+    #[error("Swap cooldown of the account is active until {available_at}")]
+    SwapCooldownActive { available_at: u64 },
+
+    #[error("Large swap delay is disabled")]
+    LargeSwapDelayDisabled {},
+
+    #[error("Only swaps can be scheduled")]
+    NotASwap {},
+
+    #[error("Scheduled swap {swap_id} not found")]
+    ScheduledSwapNotFound { swap_id: u64 },
+
+    #[error("Scheduled swap {swap_id} can't be executed before block {execute_after}")]
+    ScheduledSwapNotReady { swap_id: u64, execute_after: u64 },
+
+    #[error("Generated messages can't send the output of a swap to a recipient")]
+    OutputRecipientNotSupported {},
+
+    #[error("Route must contain at least one hop")]
+    EmptyRoute {},
+
+    #[error("Route is not contiguous, hop {hop} offers {offer_asset} but {expected} is received from the previous hop")]
+    NonContiguousRoute {
+        hop: usize,
+        offer_asset: AssetEntry,
+        expected: AssetEntry,
+    },
 }
@@ -11,7 +11,7 @@ use abstract_std::{
     AbstractError, AbstractResult,
 };
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, CosmosMsg, Decimal, Uint128};
+use cosmwasm_std::{Addr, CosmosMsg, Decimal, Timestamp, Uint128};
 use cw_asset::{AssetBase, AssetInfoBase};
 
 pub use crate::{ans_action::DexAnsAction, raw_action::DexRawAction};
@@ -51,6 +51,44 @@ pub struct SimulateSwapResponse<A = AssetEntry> {
     pub usage_fee: Uint128,
 }
 
+/// Response for simulating a swap over a route.
+#[cosmwasm_schema::cw_serde]
+pub struct SimulateRouteResponse {
+    /// Amount of the last asset of the route you would receive.
+    pub return_amount: Uint128,
+    /// Amount received after each hop of the route
+    pub hop_amounts: Vec<AnsAsset>,
+    /// Adapter fee charged for the route (paid in offer asset)
+    pub usage_fee: Uint128,
+}
+
+/// Response for querying the price impact of a swap.
+#[cosmwasm_schema::cw_serde]
+pub struct PriceImpactResponse {
+    /// Price of the offer asset, denominated in the ask asset, before the swap
+    pub pre_swap_price: Decimal,
+    /// Price of the offer asset, denominated in the ask asset, realized by the swap (excluding fees)
+    pub post_swap_price: Decimal,
+    /// Relative difference between the pre-swap and post-swap price
+    pub price_impact: Decimal,
+}
+
+/// Response for querying the last executed price of a pair.
+#[cosmwasm_schema::cw_serde]
+pub struct PriceFeedResponse {
+    /// Price executed by the last recorded swap, `None` if no swap was recorded
+    pub price: Option<RecordedPrice>,
+}
+
+/// Price executed by a swap through the adapter.
+#[cosmwasm_schema::cw_serde]
+pub struct RecordedPrice {
+    /// Price of the offer asset, denominated in the ask asset, excluding the adapter fee
+    pub price: Decimal,
+    /// Time the swap was executed at
+    pub recorded_at: Timestamp,
+}
+
 /// Response from GenerateMsgs
 #[cosmwasm_schema::cw_serde]
 pub struct GenerateMessagesResponse {
@@ -86,6 +124,156 @@ pub enum DexExecuteMsg {
         /// New recipient account for fees
         recipient_account: Option<u32>,
     },
+    /// Update the callers that are exempt from the swap fee.
+    /// Used to let protocol contracts route swaps through the adapter without being charged.
+    UpdateFeeExemptCallers {
+        /// Caller addresses to exempt
+        to_add: Vec<String>,
+        /// Caller addresses to remove from the exemption list
+        to_remove: Vec<String>,
+    },
+    /// Update the keepers, callers that are allowed to bypass the slippage protection of their swaps.
+    UpdateKeepers {
+        /// Keeper addresses to add
+        to_add: Vec<String>,
+        /// Keeper addresses to remove
+        to_remove: Vec<String>,
+    },
+    /// Update the dexes that are marked as deprecated.
+    /// Actions on a deprecated dex still succeed but emit a `deprecation_warning` attribute.
+    UpdateDeprecatedDexes {
+        /// Dexes to mark as deprecated
+        to_add: Vec<DexName>,
+        /// Dexes to remove from the deprecation list
+        to_remove: Vec<DexName>,
+    },
+    /// Update the router contract swaps on a dex are executed through.
+    /// Without a router, swaps are executed directly on the pool.
+    /// The router picks the pools it swaps on, so the liquidity and pool share checks
+    /// only apply to swaps executed directly on the pool.
+    UpdateRouter {
+        /// The dex to update the router of
+        dex: DexName,
+        /// New router contract, `None` swaps directly on the pools again
+        router: Option<String>,
+    },
+    /// Update the minimum liquidity a pool must hold of the swapped assets to be swapped on.
+    /// Minimums are set per asset in its smallest unit, so they account for its decimals.
+    /// Swaps on pools with less liquidity of an asset than its minimum are rejected.
+    UpdateMinPoolLiquidity {
+        /// Assets and the minimum liquidity of them a pool must hold
+        to_add: Vec<(AssetEntry, Uint128)>,
+        /// Assets to remove the minimum liquidity of
+        to_remove: Vec<AssetEntry>,
+    },
+    /// Update the maximum share of a pool's offer asset reserves that a single swap may offer.
+    /// Larger swaps are rejected.
+    UpdateMaxPoolShare {
+        /// New maximum share, between 0 (exclusive) and 1. `None` removes the limit
+        max_share: Option<Decimal>,
+    },
+    /// Update the caps on the volume of an asset that can be swapped through the adapter within a time window.
+    /// Caps are set per asset in its smallest unit, so they account for its decimals.
+    /// Swaps that would push the volume of their offer asset over its cap are rejected,
+    /// assets without a cap can be swapped without limit.
+    /// Volumes swapped before a cap was set don't count towards it.
+    UpdateVolumeCaps {
+        /// Assets and the cap on their volume
+        to_add: Vec<(AssetEntry, VolumeCap)>,
+        /// Assets to lift the cap of
+        to_remove: Vec<AssetEntry>,
+    },
+    /// Update the secondary ANS host, used to resolve ANS actions the primary ANS host fails to resolve.
+    /// Only [`DexExecuteMsg::AnsAction`] requests and the [`DexQueryMsg::GenerateMessages`]
+    /// query fall back to it. The other queries, the asset lists, the minimum pool liquidity,
+    /// the volume caps, the large swap thresholds and the quote cache only look up assets on
+    /// the primary ANS host. Assets that are only registered on the secondary ANS host therefore
+    /// aren't matched by the blocklist, the minimum pool liquidity, the volume caps or the large
+    /// swap thresholds.
+    UpdateSecondaryAnsHost {
+        /// New secondary ANS host, `None` removes the fallback
+        ans_host: Option<String>,
+    },
+    /// Update how the asset lists restrict the assets that can be swapped.
+    UpdateAssetListMode {
+        /// New mode, `None` lifts the restriction
+        mode: Option<AssetListMode>,
+    },
+    /// Update the assets that can be swapped in [`AssetListMode::Allowlist`] mode.
+    UpdateAllowedAssets {
+        /// Assets to allow
+        to_add: Vec<AssetEntry>,
+        /// Assets to remove from the allowlist
+        to_remove: Vec<AssetEntry>,
+    },
+    /// Update the assets that can't be swapped in [`AssetListMode::Blocklist`] mode.
+    UpdateBlockedAssets {
+        /// Assets to block
+        to_add: Vec<AssetEntry>,
+        /// Assets to remove from the blocklist
+        to_remove: Vec<AssetEntry>,
+    },
+    /// Update how long a cached [`DexQueryMsg::SimulateSwap`] result is served.
+    /// Within the TTL a cached quote is returned even if the pool moved since it was cached,
+    /// so a longer TTL trades quote accuracy for less simulation work.
+    /// Cached quotes of a pair are dropped whenever the adapter swaps on it.
+    UpdateQuoteCacheTtl {
+        /// New TTL in seconds, `None` disables the cache
+        ttl: Option<u64>,
+    },
+    /// Simulate a swap and cache the result for [`DexQueryMsg::SimulateSwap`].
+    /// Can only be called by keepers while the quote cache is enabled.
+    /// The cache holds a bounded number of quotes, new quotes are rejected once it's full.
+    RefreshQuote {
+        /// The asset to offer
+        offer_asset: AnsAsset,
+        /// The asset to receive
+        ask_asset: AssetEntry,
+        /// Name of the dex to simulate the swap on
+        dex: DexName,
+    },
+    /// Enable or disable recording the executed price of swaps for [`DexQueryMsg::PriceFeed`].
+    /// The recorded price is the price of the last swap, which any account can set with a
+    /// swap of any size, so it can't be used as a manipulation resistant price oracle.
+    UpdatePriceFeed {
+        /// Whether executed prices are recorded
+        enabled: bool,
+    },
+    /// Enable or disable checking that swaps returned the ask asset to the account.
+    /// Swaps that return none of the ask asset, e.g. because they were routed through a pool
+    /// of other assets, are reverted.
+    UpdateOutputValidation {
+        /// Whether the output of swaps is validated
+        enabled: bool,
+    },
+    /// Update the minimum time in seconds between the swaps of an account, `None` disables it.
+    /// Keepers and fee exempt callers aren't subject to the cooldown.
+    UpdateSwapCooldown { cooldown: Option<u64> },
+    /// Update the number of blocks between scheduling a large swap and its execution, `None` disables it.
+    /// Swaps offering more of an asset than its large swap threshold have to be scheduled while it's set.
+    UpdateLargeSwapDelay { delay: Option<u64> },
+    /// Update the offer amount of an asset above which its swaps are large.
+    /// Thresholds are set per asset in its smallest unit, so they account for its decimals.
+    /// Swaps of assets without a threshold are never delayed.
+    UpdateLargeSwapThresholds {
+        /// Assets and the offer amount above which their swaps have to be scheduled
+        to_add: Vec<(AssetEntry, Uint128)>,
+        /// Assets to remove the threshold of
+        to_remove: Vec<AssetEntry>,
+    },
+    /// Schedule a swap that offers more than the large swap threshold of its offer asset.
+    /// The swap can be executed with [`DexExecuteMsg::ExecuteScheduledSwap`] once the delay elapsed,
+    /// its id is returned in the `swap_id` attribute.
+    ScheduleLargeSwap {
+        /// The name of the dex to swap on
+        dex: DexName,
+        /// The swap to perform
+        action: DexAnsAction,
+    },
+    /// Execute a scheduled swap of the account, its slippage is checked at execution
+    ExecuteScheduledSwap { swap_id: u64 },
+    /// Cancel a scheduled swap of the account before it's executed
+    CancelScheduledSwap { swap_id: u64 },
     /// Action to perform on the DEX with ans asset denomination
     AnsAction {
         /// The name of the dex to interact with
@@ -102,6 +290,25 @@ pub enum DexExecuteMsg {
     },
 }
 
+/// Cap on the volume of an asset swapped through the adapter by all accounts combined
+#[cosmwasm_schema::cw_serde]
+pub struct VolumeCap {
+    /// Maximum amount of the asset that can be offered within a window, can't be zero
+    pub max_volume: Uint128,
+    /// Length of a window in seconds, can't be zero.
+    /// A new window starts with the first swap after the previous one ended
+    pub window: u64,
+}
+
+/// Policy used to restrict the assets that can be swapped
+#[cosmwasm_schema::cw_serde]
+pub enum AssetListMode {
+    /// Only assets on the allowlist can be swapped
+    Allowlist,
+    /// All assets except the ones on the blocklist can be swapped
+    Blocklist,
+}
+
 /// Query messages for the dex adapter
 #[cosmwasm_schema::cw_serde]
 #[derive(QueryResponses, cw_orch::QueryFns)]
@@ -131,7 +338,46 @@ pub enum DexQueryMsg {
         /// Name of the dex to simulate the swap on
         dex: DexName,
     },
+    /// Simulate a swap over multiple hops, charging the adapter fee once on the input
+    /// Returns [`SimulateRouteResponse`]
+    #[returns(SimulateRouteResponse)]
+    SimulateRoute {
+        /// The asset to offer
+        offer_asset: AssetEntry,
+        /// The hops of the route, each hop must offer the asset received from the previous hop
+        route: Vec<DexAssetPairing>,
+        /// Amount of the offer asset to swap
+        amount: Uint128,
+    },
+    /// Query how much a swap would move the price of the pool
+    /// Returns [`PriceImpactResponse`]
+    #[returns(PriceImpactResponse)]
+    PriceImpact {
+        /// The asset to offer
+        offer_asset: AssetEntry,
+        /// The asset to receive
+        ask_asset: AssetEntry,
+        /// Amount of the offer asset to swap
+        amount: Uint128,
+        /// Name of the dex to simulate the swap on
+        dex: DexName,
+    },
+    /// Query the price executed by the last swap of a pair while the price feed was enabled.
+    /// Any account can set this price with a swap of any size, including a dust swap at a bad
+    /// price, so it must not be relied on as a price oracle.
+    /// Returns [`PriceFeedResponse`]
+    #[returns(PriceFeedResponse)]
+    PriceFeed {
+        /// The offered asset
+        offer_asset: AssetEntry,
+        /// The received asset
+        ask_asset: AssetEntry,
+        /// Name of the dex the swap was executed on
+        dex: DexName,
+    },
     /// Endpoint can be used by front-end to easily interact with contracts.
+    /// Swaps with an `output_recipient` are rejected,
+    /// generated swaps leave their output with the sender.
     /// Returns [`GenerateMessagesResponse`]
     #[returns(GenerateMessagesResponse)]
     GenerateMessages {
@@ -2,7 +2,7 @@
 //! # Dex Adapter Raw Action Definition
 
 use abstract_std::objects::pool_id::UncheckedPoolAddress;
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Decimal, Uint128};
 use cw_asset::{AssetBase, AssetInfoBase};
 
 /// Possible raw actions to perform on the DEX
@@ -48,5 +48,13 @@ pub enum DexRawAction {
         max_spread: Option<Decimal>,
         /// The belief price when submitting the transaction.
         belief_price: Option<Decimal>,
+        /// The minimum amount of ask asset to receive. Can't be combined with `max_spread`
+        /// or `belief_price`.
+        min_receive: Option<Uint128>,
+        /// Ignore the slippage parameters of the swap. Only honored for keepers of the dex adapter.
+        #[serde(default)]
+        bypass_slippage: bool,
+        /// Address that receives the output of the swap, the account if unset.
+        output_recipient: Option<String>,
     },
 }
@@ -250,6 +250,36 @@ impl DexCommand for Astroport {
         Ok(vec![withdraw_msg])
     }
 
+    fn pool_liquidity(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        assets: Vec<AssetInfo>,
+    ) -> Result<Vec<Asset>, DexError> {
+        let pair_address = pool_id.expect_contract()?;
+        let pool: PoolResponse = deps.querier.query_wasm_smart(
+            pair_address.to_string(),
+            &astroport::pair::QueryMsg::Pool {},
+        )?;
+        assets
+            .into_iter()
+            .map(|info| {
+                let astroport_info = cw_asset_info_to_astroport(&info)?;
+                let reserve = pool
+                    .assets
+                    .iter()
+                    .find(|reserve| reserve.info == astroport_info)
+                    .ok_or_else(|| {
+                        DexError::ArgumentMismatch(
+                            info.to_string(),
+                            pool.assets.iter().map(|e| e.info.to_string()).collect(),
+                        )
+                    })?;
+                Ok(Asset::new(info, reserve.amount))
+            })
+            .collect()
+    }
+
     fn simulate_swap(
         &self,
         deps: Deps,
@@ -277,20 +307,22 @@ impl DexCommand for Astroport {
 
 #[cfg(feature = "full_integration")]
 fn cw_asset_to_astroport(asset: &Asset) -> Result<astroport::asset::Asset, DexError> {
-    match &asset.info {
-        AssetInfoBase::Native(denom) => Ok(astroport::asset::Asset {
-            amount: asset.amount,
-            info: astroport::asset::AssetInfo::NativeToken {
-                denom: denom.clone(),
-            },
+    Ok(astroport::asset::Asset {
+        amount: asset.amount,
+        info: cw_asset_info_to_astroport(&asset.info)?,
+    })
+}
+
+#[cfg(feature = "full_integration")]
+fn cw_asset_info_to_astroport(info: &AssetInfo) -> Result<astroport::asset::AssetInfo, DexError> {
+    match info {
+        AssetInfoBase::Native(denom) => Ok(astroport::asset::AssetInfo::NativeToken {
+            denom: denom.clone(),
         }),
-        AssetInfoBase::Cw20(contract_addr) => Ok(astroport::asset::Asset {
-            amount: asset.amount,
-            info: astroport::asset::AssetInfo::Token {
-                contract_addr: contract_addr.clone(),
-            },
+        AssetInfoBase::Cw20(contract_addr) => Ok(astroport::asset::AssetInfo::Token {
+            contract_addr: contract_addr.clone(),
         }),
-        _ => Err(DexError::UnsupportedAssetType(asset.info.to_string())),
+        _ => Err(DexError::UnsupportedAssetType(info.to_string())),
     }
 }
 
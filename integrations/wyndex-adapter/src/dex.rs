@@ -20,13 +20,16 @@ use ::{
         coins_in_assets, cw_approve_msgs, DexCommand, DexError, Fee, FeeOnInput, Return, Spread,
     },
     abstract_sdk::std::objects::PoolAddress,
-    cosmwasm_std::{to_json_binary, wasm_execute, CosmosMsg, Decimal, Deps, Uint128},
+    cosmwasm_std::{
+        to_json_binary, wasm_execute, Addr, CosmosMsg, Decimal, Deps, StdError, Uint128,
+    },
     cw20::Cw20ExecuteMsg,
     cw_asset::{Asset, AssetInfo, AssetInfoBase},
     wyndex::{
         asset::{AssetInfoValidated, AssetValidated},
         pair::*,
     },
+    wyndex_multi_hop::msg::{ExecuteMsg as MultiHopExecuteMsg, SwapOperation},
 };
 
 #[cfg(feature = "full_integration")]
@@ -79,6 +82,60 @@ impl DexCommand for WynDex {
         Ok(swap_msg)
     }
 
+    fn swap_via_router(
+        &self,
+        _deps: Deps,
+        router: Addr,
+        offer_asset: Asset,
+        ask_asset: AssetInfo,
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+    ) -> Result<Vec<CosmosMsg>, DexError> {
+        // The multi-hop contract has no belief price, so it's expressed as the minimum to receive.
+        // The caller's max spread is still passed on to the spread check of the pairs.
+        let minimum_receive = belief_price
+            .map(|belief_price| {
+                let tolerance = Decimal::one() - max_spread.unwrap_or_default();
+                tolerance
+                    .checked_div(belief_price)
+                    .map(|min_price| offer_asset.amount * min_price)
+                    .map_err(|e| StdError::generic_err(e.to_string()))
+            })
+            .transpose()?;
+        let swap_operations = MultiHopExecuteMsg::ExecuteSwapOperations {
+            operations: vec![SwapOperation::WyndexSwap {
+                offer_asset_info: cw_asset_info_to_wyndex(&offer_asset.info)?,
+                ask_asset_info: cw_asset_info_to_wyndex(&ask_asset)?,
+            }],
+            minimum_receive,
+            receiver: None,
+            max_spread,
+            referral_address: None,
+            referral_commission: None,
+        };
+
+        let swap_msg: CosmosMsg = match &offer_asset.info {
+            AssetInfo::Native(_) => wasm_execute(
+                router.to_string(),
+                &swap_operations,
+                vec![offer_asset.clone().try_into()?],
+            )?
+            .into(),
+            AssetInfo::Cw20(addr) => wasm_execute(
+                addr.to_string(),
+                &Cw20ExecuteMsg::Send {
+                    contract: router.to_string(),
+                    amount: offer_asset.amount,
+                    msg: to_json_binary(&swap_operations)?,
+                },
+                vec![],
+            )?
+            .into(),
+            _ => return Err(DexError::UnsupportedAssetType(offer_asset.info.to_string())),
+        };
+        Ok(vec![swap_msg])
+    }
+
     fn provide_liquidity(
         &self,
         deps: Deps,
@@ -241,6 +298,35 @@ impl DexCommand for WynDex {
         Ok(vec![withdraw_msg])
     }
 
+    fn pool_liquidity(
+        &self,
+        deps: Deps,
+        pool_id: PoolAddress,
+        assets: Vec<AssetInfo>,
+    ) -> Result<Vec<Asset>, DexError> {
+        let pair_address = pool_id.expect_contract()?;
+        let pool: PoolResponse = deps
+            .querier
+            .query_wasm_smart(pair_address.to_string(), &QueryMsg::Pool {})?;
+        assets
+            .into_iter()
+            .map(|info| {
+                let wyndex_info = cw_asset_info_to_wyndex_valid(&info)?;
+                let reserve = pool
+                    .assets
+                    .iter()
+                    .find(|reserve| reserve.info == wyndex_info)
+                    .ok_or_else(|| {
+                        DexError::ArgumentMismatch(
+                            info.to_string(),
+                            pool.assets.iter().map(|e| e.info.to_string()).collect(),
+                        )
+                    })?;
+                Ok(Asset::new(info, reserve.amount))
+            })
+            .collect()
+    }
+
     fn simulate_swap(
         &self,
         deps: Deps,
@@ -284,6 +370,26 @@ fn cw_asset_to_wyndex(asset: &Asset) -> Result<wyndex::asset::Asset, DexError> {
     }
 }
 
+#[cfg(feature = "full_integration")]
+fn cw_asset_info_to_wyndex(info: &AssetInfo) -> Result<wyndex::asset::AssetInfo, DexError> {
+    match info {
+        AssetInfoBase::Native(denom) => Ok(wyndex::asset::AssetInfo::Native(denom.clone())),
+        AssetInfoBase::Cw20(contract_addr) => {
+            Ok(wyndex::asset::AssetInfo::Token(contract_addr.to_string()))
+        }
+        _ => Err(DexError::UnsupportedAssetType(info.to_string())),
+    }
+}
+
+#[cfg(feature = "full_integration")]
+fn cw_asset_info_to_wyndex_valid(info: &AssetInfo) -> Result<AssetInfoValidated, DexError> {
+    match info {
+        AssetInfoBase::Native(denom) => Ok(AssetInfoValidated::Native(denom.clone())),
+        AssetInfoBase::Cw20(contract_addr) => Ok(AssetInfoValidated::Token(contract_addr.clone())),
+        _ => Err(DexError::UnsupportedAssetType(info.to_string())),
+    }
+}
+
 #[cfg(feature = "full_integration")]
 fn cw_asset_to_wyndex_valid(asset: &Asset) -> Result<AssetValidated, DexError> {
     match &asset.info {
@@ -385,6 +385,8 @@ mod test {
                         base_asset: None,
                         namespace: None,
                         install_modules: vec![],
+                        proxy_admin: None,
+                        linked_contracts: None,
                     },
                     vec![],
                 )?
@@ -455,6 +457,8 @@ mod test {
             None,
             None,
             None,
+            None,
+            None,
             &[],
         )?)
     }
@@ -43,10 +43,13 @@ pub fn abstract_ibc_connection_with<Chain: IbcQueryHandler, IBC: InterchainEnv<C
     )?;
 
     dest.account_factory.update_config(
+        None,
+        None,
         None,
         Some(dest.ibc.host.address()?.to_string()),
         None,
         None,
+        None,
     )?;
 
     Ok(())
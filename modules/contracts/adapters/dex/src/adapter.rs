@@ -3,12 +3,18 @@ use abstract_adapter::sdk::{
     features::{AbstractNameService, AbstractRegistryAccess},
     Execution,
 };
-use abstract_adapter::std::objects::pool_id::PoolAddressBase;
-use abstract_dex_standard::{raw_action::DexRawAction, DexCommand, DexError};
-use cosmwasm_std::{Addr, CosmosMsg, Decimal, Deps};
-use cw_asset::{AssetBase, AssetInfoBase};
+use abstract_adapter::std::objects::{
+    ans_host::{AnsHost, AnsHostError},
+    pool_id::PoolAddressBase,
+};
+use abstract_dex_standard::{msg::AssetListMode, raw_action::DexRawAction, DexCommand, DexError};
+use cosmwasm_std::{ensure, Addr, CosmosMsg, Decimal, Deps, StdError, Uint128};
+use cw_asset::{AssetBase, AssetInfo, AssetInfoBase};
 
-use crate::state::DEX_FEES;
+use crate::state::{
+    ALLOWED_ASSETS, ASSET_LIST_MODE, BLOCKED_ASSETS, DEX_FEES, MAX_POOL_SHARE, MIN_POOL_LIQUIDITY,
+    ROUTERS,
+};
 
 pub const PROVIDE_LIQUIDITY: u64 = 7542;
 pub const PROVIDE_LIQUIDITY_SYM: u64 = 7543;
@@ -16,6 +22,93 @@ pub const WITHDRAW_LIQUIDITY: u64 = 7546;
 pub const SWAP: u64 = 7544;
 pub const CUSTOM_SWAP: u64 = 7545;
 
+/// Converts the user-provided slippage parameters to the `(belief_price, max_spread)` pair
+/// understood by the dexes.
+///
+/// A `min_receive` is expressed as a belief price of `offer_amount / min_receive` with zero
+/// tolerated spread, which makes the dex reject any swap returning less than `min_receive`.
+pub(crate) fn slippage_params(
+    offer_amount: Uint128,
+    max_spread: Option<Decimal>,
+    belief_price: Option<Decimal>,
+    min_receive: Option<Uint128>,
+) -> Result<(Option<Decimal>, Option<Decimal>), DexError> {
+    match (max_spread, belief_price, min_receive) {
+        (None, None, Some(min_receive)) => {
+            let belief_price = Decimal::checked_from_ratio(offer_amount, min_receive)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            Ok((Some(belief_price), Some(Decimal::zero())))
+        }
+        (_, _, Some(_)) => Err(DexError::ConflictingSlippageParams {}),
+        (max_spread, belief_price, None) => Ok((belief_price, max_spread)),
+    }
+}
+
+/// Asserts that the assets are permitted by the configured asset list mode.
+fn assert_assets_permitted<'a>(
+    deps: Deps,
+    ans_host: &AnsHost,
+    assets: impl IntoIterator<Item = &'a AssetInfo>,
+) -> Result<(), DexError> {
+    let Some(mode) = ASSET_LIST_MODE.may_load(deps.storage)? else {
+        return Ok(());
+    };
+    for info in assets {
+        // Assets that are not registered on the ans host can't be listed
+        let entry = match ans_host.query_asset_reverse(&deps.querier, info) {
+            Ok(entry) => Some(entry),
+            Err(AnsHostError::CwAssetNotFound { .. }) => None,
+            Err(e) => return Err(e.into()),
+        };
+        match mode {
+            AssetListMode::Allowlist => {
+                let allowed = entry
+                    .as_ref()
+                    .is_some_and(|entry| ALLOWED_ASSETS.has(deps.storage, entry));
+                ensure!(
+                    allowed,
+                    DexError::AssetNotAllowed {
+                        asset: info.to_string()
+                    }
+                );
+            }
+            AssetListMode::Blocklist => {
+                if let Some(entry) = entry {
+                    ensure!(
+                        !BLOCKED_ASSETS.has(deps.storage, &entry),
+                        DexError::AssetBlocked {
+                            asset: info.to_string()
+                        }
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the minimum liquidity of each of the assets a pool must hold.
+/// Assets that are not registered on the ans host have no minimum.
+fn min_pool_liquidity(
+    deps: Deps,
+    ans_host: &AnsHost,
+    assets: [&AssetInfo; 2],
+) -> Result<Vec<Option<Uint128>>, DexError> {
+    if MIN_POOL_LIQUIDITY.is_empty(deps.storage) {
+        return Ok(vec![None; assets.len()]);
+    }
+    assets
+        .into_iter()
+        .map(
+            |info| match ans_host.query_asset_reverse(&deps.querier, info) {
+                Ok(entry) => Ok(MIN_POOL_LIQUIDITY.may_load(deps.storage, &entry)?),
+                Err(AnsHostError::CwAssetNotFound { .. }) => Ok(None),
+                Err(e) => Err(e.into()),
+            },
+        )
+        .collect()
+}
+
 impl<T> DexAdapter for T where T: AbstractNameService + Execution + AbstractRegistryAccess {}
 
 pub(crate) type ReplyId = u64;
@@ -26,6 +119,8 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
         &self,
         deps: Deps,
         sender: Addr,
+        fee_exempt: bool,
+        keeper: bool,
         action: DexRawAction,
         mut exchange: Box<dyn DexCommand>,
     ) -> Result<(Vec<CosmosMsg>, ReplyId), DexError> {
@@ -80,16 +175,23 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
                 ask_asset,
                 max_spread,
                 belief_price,
+                min_receive,
+                bypass_slippage,
+                ..
             } => (
                 self.resolve_swap(
                     deps,
                     sender,
+                    fee_exempt,
                     offer_asset,
                     ask_asset,
                     pool,
                     exchange.as_mut(),
                     max_spread,
                     belief_price,
+                    min_receive,
+                    // only keepers can bypass the slippage protection
+                    bypass_slippage && keeper,
                 )?,
                 SWAP,
             ),
@@ -101,21 +203,38 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
         &self,
         deps: Deps,
         sender: Addr,
+        fee_exempt: bool,
         offer_asset: AssetBase<String>,
         ask_asset: AssetInfoBase<String>,
         pool: PoolAddressBase<String>,
         exchange: &mut dyn DexCommand,
         max_spread: Option<Decimal>,
         belief_price: Option<Decimal>,
+        min_receive: Option<Uint128>,
+        bypass_slippage: bool,
     ) -> Result<Vec<CosmosMsg>, DexError> {
         let pool_address = pool.check(deps.api)?;
         let mut offer_asset = offer_asset.check(deps.api, None)?;
         let ask_asset = ask_asset.check(deps.api, None)?;
 
+        assert_assets_permitted(deps, &self.ans_host(deps)?, [&offer_asset.info, &ask_asset])?;
+
         // account for fee
-        let dex_fees = DEX_FEES.load(deps.storage)?;
-        let usage_fee = dex_fees.swap_usage_fee()?;
-        let fee_msg = offer_asset.charge_usage_fee(usage_fee)?;
+        let fee_msg = if fee_exempt {
+            None
+        } else {
+            let dex_fees = DEX_FEES.load(deps.storage)?;
+            let usage_fee = dex_fees.swap_usage_fee()?;
+            offer_asset.charge_usage_fee(usage_fee)?
+        };
+
+        // the slippage is checked against the amount that is actually offered to the dex
+        let (belief_price, max_spread) = if bypass_slippage {
+            // leave the slippage protection to the defaults of the dex
+            (None, None)
+        } else {
+            slippage_params(offer_asset.amount, max_spread, belief_price, min_receive)?
+        };
 
         exchange.fetch_data(
             deps,
@@ -123,14 +242,64 @@ pub trait DexAdapter: AbstractNameService + AbstractRegistryAccess + Execution {
             self.abstract_registry(deps)?,
             self.ans_host(deps)?,
         )?;
-        let mut swap_msgs = exchange.swap(
-            deps,
-            pool_address,
-            offer_asset,
-            ask_asset,
-            belief_price,
-            max_spread,
-        )?;
+        let mut swap_msgs = match ROUTERS.may_load(deps.storage, exchange.name())? {
+            // the router picks the pools it swaps on, so the checks of the given pool don't apply
+            Some(router) => exchange.swap_via_router(
+                deps,
+                router,
+                offer_asset,
+                ask_asset,
+                belief_price,
+                max_spread,
+            )?,
+            None => {
+                let min_liquidity = min_pool_liquidity(
+                    deps,
+                    &self.ans_host(deps)?,
+                    [&offer_asset.info, &ask_asset],
+                )?;
+                let max_pool_share = MAX_POOL_SHARE.may_load(deps.storage)?;
+                if min_liquidity.iter().any(Option::is_some) || max_pool_share.is_some() {
+                    let liquidity = exchange.pool_liquidity(
+                        deps,
+                        pool_address.clone(),
+                        vec![offer_asset.info.clone(), ask_asset.clone()],
+                    )?;
+                    // reject swaps on pools that are too shallow
+                    for (reserve, min_liquidity) in liquidity.iter().zip(min_liquidity) {
+                        if let Some(min_liquidity) = min_liquidity {
+                            ensure!(
+                                reserve.amount >= min_liquidity,
+                                DexError::InsufficientPoolLiquidity {
+                                    pool: pool_address.to_string(),
+                                    liquidity: reserve.to_string(),
+                                    min_liquidity,
+                                }
+                            );
+                        }
+                    }
+                    // reject swaps that would consume too much of the offer asset reserves
+                    if let Some(max_pool_share) = max_pool_share {
+                        let max_offer_amount = liquidity[0].amount * max_pool_share;
+                        ensure!(
+                            offer_asset.amount <= max_offer_amount,
+                            DexError::SwapTooLargeForPool {
+                                offer_amount: offer_asset.amount,
+                                max_offer_amount,
+                            }
+                        );
+                    }
+                }
+                exchange.swap(
+                    deps,
+                    pool_address,
+                    offer_asset,
+                    ask_asset,
+                    belief_price,
+                    max_spread,
+                )?
+            }
+        };
         // insert fee msg
         if let Some(f) = fee_msg {
             swap_msgs.push(f)
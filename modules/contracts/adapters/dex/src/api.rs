@@ -4,7 +4,7 @@ use abstract_adapter::sdk::{
     AbstractSdkResult, AdapterInterface,
 };
 use abstract_adapter::std::objects::{module::ModuleId, AnsAsset, AssetEntry, PoolAddress};
-use abstract_dex_standard::msg::GenerateMessagesResponse;
+use abstract_dex_standard::msg::{GenerateMessagesResponse, PriceImpactResponse};
 use abstract_dex_standard::{
     ans_action::DexAnsAction,
     msg::{DexExecuteMsg, DexName, DexQueryMsg, SimulateSwapResponse},
@@ -104,6 +104,9 @@ pub mod raw {
                 offer_asset: offer_asset.into(),
                 ask_asset: ask_asset.into(),
                 belief_price,
+                min_receive: None,
+                bypass_slippage: false,
+                output_recipient: None,
                 max_spread,
                 pool: pool.into(),
             })
@@ -192,6 +195,9 @@ pub mod raw {
                         ask_asset: ask_asset.into(),
                         max_spread,
                         belief_price,
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                         pool: pool.into(),
                     },
                 },
@@ -264,6 +270,9 @@ pub mod ans {
                 offer_asset,
                 ask_asset,
                 belief_price,
+                min_receive: None,
+                bypass_slippage: false,
+                output_recipient: None,
                 max_spread,
             })
         }
@@ -316,6 +325,21 @@ pub mod ans {
             Ok(response)
         }
 
+        /// Query how much a swap would move the price of the pool
+        pub fn price_impact(
+            &self,
+            offer_asset: AnsAsset,
+            ask_asset: AssetEntry,
+        ) -> AbstractSdkResult<PriceImpactResponse> {
+            let response: PriceImpactResponse = self.query(DexQueryMsg::PriceImpact {
+                dex: self.dex_name(),
+                offer_asset: offer_asset.name,
+                ask_asset,
+                amount: offer_asset.amount,
+            })?;
+            Ok(response)
+        }
+
         /// Generate the raw messages that are need to run a swap
         pub fn generate_swap_messages(
             &self,
@@ -333,6 +357,9 @@ pub mod ans {
                         ask_asset,
                         max_spread,
                         belief_price,
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                     },
                 },
                 addr_as_sender: addr_as_sender.into(),
@@ -384,6 +411,9 @@ mod test {
                 ask_asset: ask_asset.clone(),
                 max_spread,
                 belief_price,
+                min_receive: None,
+                bypass_slippage: false,
+                output_recipient: None,
             },
         });
 
@@ -554,6 +584,9 @@ mod test {
                     ask_asset: ask_asset.clone().into(),
                     max_spread,
                     belief_price,
+                    min_receive: None,
+                    bypass_slippage: false,
+                    output_recipient: None,
                     pool: pool.clone().into(),
                 },
             });
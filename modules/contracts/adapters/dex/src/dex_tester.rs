@@ -124,6 +124,9 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                         ask_asset: AssetEntry::new(&ans_asset_b),
                         max_spread: None,
                         belief_price: None,
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                     },
                 },
             }),
@@ -147,6 +150,9 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                         ask_asset: AssetEntry::new(&ans_asset_a),
                         max_spread: None,
                         belief_price: None,
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                     },
                 },
             }),
@@ -192,6 +198,9 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                         ask_asset: AssetEntry::new(&ans_asset_b),
                         max_spread: Some(Decimal::percent(10)),
                         belief_price: Some(belief_price_a_to_b),
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                     },
                 },
             }),
@@ -215,6 +224,9 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                         ask_asset: AssetEntry::new(&ans_asset_a),
                         max_spread: Some(Decimal::percent(10)),
                         belief_price: Some(belief_price_b_to_a),
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                     },
                 },
             }),
@@ -240,6 +252,9 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                         ask_asset: AssetEntry::new(&ans_asset_b),
                         max_spread: Some(Decimal::percent(10)),
                         belief_price: Some(Decimal::from_ratio(1u128, 4242u128)),
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                     },
                 },
             }),
@@ -258,6 +273,9 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                         ask_asset: AssetEntry::new(&ans_asset_a),
                         max_spread: Some(Decimal::percent(10)),
                         belief_price: Some(Decimal::from_ratio(1u128, 424242u128)),
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                     },
                 },
             }),
@@ -718,6 +736,9 @@ impl<Chain: MutCwEnv, Dex: MockDex> DexTester<Chain, Dex> {
                         ask_asset,
                         max_spread: None,
                         belief_price: None,
+                        min_receive: None,
+                        bypass_slippage: false,
+                        output_recipient: None,
                     },
                 },
                 addr_as_sender: proxy_addr.to_string(),
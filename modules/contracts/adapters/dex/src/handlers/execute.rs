@@ -1,15 +1,15 @@
 use abstract_adapter::sdk::{
     features::AbstractNameService, AccountVerification, Execution, IbcInterface,
-    ModuleRegistryInterface,
+    ModuleRegistryInterface, Resolve,
 };
 use abstract_adapter::std::{
     ibc::CallbackInfo,
     objects::{
         account::AccountTrace,
-        ans_host::AnsHost,
+        ans_host::{AnsHost, AnsHostError},
         chain_name::ChainName,
         namespace::{Namespace, ABSTRACT_NAMESPACE},
-        AccountId,
+        AccountId, DexAssetPairing,
     },
 };
 use abstract_dex_standard::{
@@ -19,16 +19,24 @@ use abstract_dex_standard::{
     DexError, DEX_ADAPTER_ID,
 };
 use cosmwasm_std::{
-    ensure_eq, to_json_binary, Coin, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    ensure, ensure_eq, to_json_binary, Addr, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Order,
+    ReplyOn, Response, StdError, StdResult, Uint128,
 };
-use cw_asset::AssetBase;
+use cw_asset::{AssetBase, AssetInfoBase};
 
 use crate::{
     contract::{DexAdapter, DexResult},
     exchanges::exchange_resolver,
-    handlers::execute::exchange_resolver::is_over_ibc,
+    handlers::{execute::exchange_resolver::is_over_ibc, query::simulate_ans_swap},
     msg::{DexExecuteMsg, DexName},
-    state::DEX_FEES,
+    state::{
+        CachedQuote, PendingSwap, ScheduledSwap, WindowVolume, ALLOWED_ASSETS, ASSET_LIST_MODE,
+        BLOCKED_ASSETS, DEPRECATED_DEXES, DEX_FEES, FEE_EXEMPT_CALLERS, KEEPERS, LARGE_SWAP_DELAY,
+        LARGE_SWAP_THRESHOLDS, LAST_SWAPS, MAX_CACHED_QUOTES, MAX_POOL_SHARE, MIN_POOL_LIQUIDITY,
+        NEXT_SCHEDULED_SWAP_ID, OUTPUT_VALIDATION_ENABLED, PENDING_SWAP, PRICE_FEED,
+        PRICE_FEED_ENABLED, QUOTE_CACHE, QUOTE_CACHE_TTL, ROUTERS, SCHEDULED_SWAPS,
+        SECONDARY_ANS_HOST, SWAP_COOLDOWN, SWAP_VOLUMES, VOLUME_CAPS,
+    },
 };
 
 use abstract_adapter::sdk::features::AccountIdentification;
@@ -48,15 +56,14 @@ pub fn execute_handler(
             let (local_dex_name, is_over_ibc) = is_over_ibc(env.clone(), &dex_name)?;
             // We resolve the Action to a RawAction to get the actual addresses, ids and denoms
             let whole_dex_action = WholeDexAction(local_dex_name.clone(), action);
-            let ans = adapter.name_service(deps.as_ref());
-            let raw_action = ans.query(&whole_dex_action)?;
+            let raw_action = resolve_ans_action(deps.as_ref(), &adapter, &whole_dex_action)?;
 
             // if exchange is on an app-chain, execute the action on the app-chain
             if is_over_ibc {
                 handle_ibc_request(&deps, info, &adapter, local_dex_name, &raw_action)
             } else {
                 // the action can be executed on the local chain
-                handle_local_request(deps, env, info, &adapter, local_dex_name, raw_action)
+                handle_local_request(deps, env, info, &adapter, local_dex_name, raw_action, false)
             }
         }
         DexExecuteMsg::RawAction {
@@ -70,25 +77,82 @@ pub fn execute_handler(
                 handle_ibc_request(&deps, info, &adapter, local_dex_name, &action)
             } else {
                 // the action can be executed on the local chain
-                handle_local_request(deps, env, info, &adapter, local_dex_name, action)
+                handle_local_request(deps, env, info, &adapter, local_dex_name, action, false)
             }
         }
+        DexExecuteMsg::ScheduleLargeSwap {
+            dex: dex_name,
+            action,
+        } => {
+            let delay = LARGE_SWAP_DELAY
+                .may_load(deps.storage)?
+                .ok_or(DexError::LargeSwapDelayDisabled {})?;
+            let (local_dex_name, is_over_ibc) = is_over_ibc(env.clone(), &dex_name)?;
+            ensure!(!is_over_ibc, DexError::ForeignDex(dex_name));
+
+            let whole_dex_action = WholeDexAction(local_dex_name.clone(), action);
+            let action = resolve_ans_action(deps.as_ref(), &adapter, &whole_dex_action)?;
+            ensure!(
+                matches!(action, DexRawAction::Swap { .. }),
+                DexError::NotASwap {}
+            );
+
+            let proxy = adapter.account_base(deps.as_ref())?.proxy;
+            let swap_id = NEXT_SCHEDULED_SWAP_ID
+                .may_load(deps.storage)?
+                .unwrap_or_default();
+            NEXT_SCHEDULED_SWAP_ID.save(deps.storage, &(swap_id + 1))?;
+            SCHEDULED_SWAPS.save(
+                deps.storage,
+                (&proxy, swap_id),
+                &ScheduledSwap {
+                    dex: local_dex_name,
+                    action,
+                    execute_after: env.block.height + delay,
+                },
+            )?;
+            Ok(Response::new().add_attribute("swap_id", swap_id.to_string()))
+        }
+        DexExecuteMsg::ExecuteScheduledSwap { swap_id } => {
+            let proxy = adapter.account_base(deps.as_ref())?.proxy;
+            let scheduled_swap = SCHEDULED_SWAPS
+                .may_load(deps.storage, (&proxy, swap_id))?
+                .ok_or(DexError::ScheduledSwapNotFound { swap_id })?;
+            ensure!(
+                env.block.height >= scheduled_swap.execute_after,
+                DexError::ScheduledSwapNotReady {
+                    swap_id,
+                    execute_after: scheduled_swap.execute_after,
+                }
+            );
+            SCHEDULED_SWAPS.remove(deps.storage, (&proxy, swap_id));
+
+            // the slippage of the swap is checked against the current state of the pool
+            handle_local_request(
+                deps,
+                env,
+                info,
+                &adapter,
+                scheduled_swap.dex,
+                scheduled_swap.action,
+                true,
+            )
+        }
+        DexExecuteMsg::CancelScheduledSwap { swap_id } => {
+            let proxy = adapter.account_base(deps.as_ref())?.proxy;
+            ensure!(
+                SCHEDULED_SWAPS.has(deps.storage, (&proxy, swap_id)),
+                DexError::ScheduledSwapNotFound { swap_id }
+            );
+            SCHEDULED_SWAPS.remove(deps.storage, (&proxy, swap_id));
+            Ok(Response::default())
+        }
         DexExecuteMsg::UpdateFee {
             swap_fee,
             recipient_account: recipient_account_id,
         } => {
             // Only namespace owner (abstract) can change recipient address
-            let namespace = adapter
-                .module_registry(deps.as_ref())?
-                .query_namespace(Namespace::new(ABSTRACT_NAMESPACE)?)?;
-
-            // unwrap namespace, since it's unlikely to have unclaimed abstract namespace
-            let namespace_info = namespace.unwrap();
-            ensure_eq!(
-                namespace_info.account_base,
-                adapter.target_account.clone().unwrap(),
-                DexError::Unauthorized {}
-            );
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
             let mut fee = DEX_FEES.load(deps.storage)?;
 
             // Update swap fee
@@ -107,31 +171,557 @@ pub fn execute_handler(
             DEX_FEES.save(deps.storage, &fee)?;
             Ok(Response::default())
         }
+        DexExecuteMsg::UpdateFeeExemptCallers { to_add, to_remove } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            for caller in to_add {
+                let caller = deps.api.addr_validate(&caller)?;
+                FEE_EXEMPT_CALLERS.save(deps.storage, &caller, &())?;
+            }
+            for caller in to_remove {
+                let caller = deps.api.addr_validate(&caller)?;
+                FEE_EXEMPT_CALLERS.remove(deps.storage, &caller);
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateRouter { dex, router } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            // make sure the dex is known
+            exchange_resolver::identify_exchange(&dex)?;
+            match router {
+                Some(router) => {
+                    let router = deps.api.addr_validate(&router)?;
+                    ROUTERS.save(deps.storage, &dex, &router)?
+                }
+                None => ROUTERS.remove(deps.storage, &dex),
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateMinPoolLiquidity { to_add, to_remove } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            for (asset, min_liquidity) in to_add {
+                MIN_POOL_LIQUIDITY.save(deps.storage, &asset, &min_liquidity)?;
+            }
+            for asset in to_remove {
+                MIN_POOL_LIQUIDITY.remove(deps.storage, &asset);
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateMaxPoolShare { max_share } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            match max_share {
+                Some(max_share) => {
+                    ensure!(
+                        !max_share.is_zero() && max_share <= Decimal::one(),
+                        DexError::InvalidMaxPoolShare {}
+                    );
+                    MAX_POOL_SHARE.save(deps.storage, &max_share)?
+                }
+                None => MAX_POOL_SHARE.remove(deps.storage),
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateVolumeCaps { to_add, to_remove } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            for (asset, volume_cap) in to_add {
+                ensure!(
+                    !volume_cap.max_volume.is_zero() && volume_cap.window > 0,
+                    DexError::InvalidVolumeCap {}
+                );
+                VOLUME_CAPS.save(deps.storage, &asset, &volume_cap)?;
+                // the new cap starts with a fresh window
+                SWAP_VOLUMES.remove(deps.storage, &asset);
+            }
+            for asset in to_remove {
+                VOLUME_CAPS.remove(deps.storage, &asset);
+                SWAP_VOLUMES.remove(deps.storage, &asset);
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateSecondaryAnsHost { ans_host } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            match ans_host {
+                Some(ans_host) => {
+                    let ans_host = AnsHost::new(deps.api.addr_validate(&ans_host)?);
+                    SECONDARY_ANS_HOST.save(deps.storage, &ans_host)?
+                }
+                None => SECONDARY_ANS_HOST.remove(deps.storage),
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateAssetListMode { mode } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            match mode {
+                Some(mode) => ASSET_LIST_MODE.save(deps.storage, &mode)?,
+                None => ASSET_LIST_MODE.remove(deps.storage),
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateAllowedAssets { to_add, to_remove } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            for asset in to_add {
+                ALLOWED_ASSETS.save(deps.storage, &asset, &())?;
+            }
+            for asset in to_remove {
+                ALLOWED_ASSETS.remove(deps.storage, &asset);
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateBlockedAssets { to_add, to_remove } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            for asset in to_add {
+                BLOCKED_ASSETS.save(deps.storage, &asset, &())?;
+            }
+            for asset in to_remove {
+                BLOCKED_ASSETS.remove(deps.storage, &asset);
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateKeepers { to_add, to_remove } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            for keeper in to_add {
+                let keeper = deps.api.addr_validate(&keeper)?;
+                KEEPERS.save(deps.storage, &keeper, &())?;
+            }
+            for keeper in to_remove {
+                let keeper = deps.api.addr_validate(&keeper)?;
+                KEEPERS.remove(deps.storage, &keeper);
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateQuoteCacheTtl { ttl } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            match ttl {
+                Some(ttl) => QUOTE_CACHE_TTL.save(deps.storage, &ttl)?,
+                None => {
+                    QUOTE_CACHE_TTL.remove(deps.storage);
+                    QUOTE_CACHE.clear(deps.storage);
+                }
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::RefreshQuote {
+            offer_asset,
+            ask_asset,
+            dex,
+        } => {
+            ensure!(
+                KEEPERS.has(deps.storage, &info.sender),
+                DexError::NotKeeper {}
+            );
+            ensure!(
+                QUOTE_CACHE_TTL.exists(deps.storage),
+                DexError::QuoteCacheDisabled {}
+            );
+
+            // quotes are cached under the local dex name, which swaps invalidate
+            let (local_dex_name, is_over_ibc) = is_over_ibc(env.clone(), &dex)?;
+            ensure!(!is_over_ibc, DexError::ForeignDex(dex));
+
+            let amount = offer_asset.amount.u128();
+            let cached = CachedQuote {
+                cached_at: env.block.time,
+                quote: simulate_ans_swap(
+                    deps.as_ref(),
+                    env,
+                    &adapter,
+                    offer_asset,
+                    ask_asset,
+                    local_dex_name,
+                )?,
+            };
+            let key = (&cached.quote.pool, amount);
+            if !QUOTE_CACHE.has(deps.storage, key) {
+                let cached_quotes = QUOTE_CACHE
+                    .keys_raw(deps.storage, None, None, Order::Ascending)
+                    .take(MAX_CACHED_QUOTES)
+                    .count();
+                ensure!(
+                    cached_quotes < MAX_CACHED_QUOTES,
+                    DexError::QuoteCacheFull {
+                        max: MAX_CACHED_QUOTES
+                    }
+                );
+            }
+            QUOTE_CACHE.save(deps.storage, key, &cached)?;
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdatePriceFeed { enabled } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            PRICE_FEED_ENABLED.save(deps.storage, &enabled)?;
+            if !enabled {
+                PRICE_FEED.clear(deps.storage);
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateSwapCooldown { cooldown } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            match cooldown {
+                Some(cooldown) => SWAP_COOLDOWN.save(deps.storage, &cooldown)?,
+                None => {
+                    SWAP_COOLDOWN.remove(deps.storage);
+                    LAST_SWAPS.clear(deps.storage);
+                }
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateLargeSwapDelay { delay } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            match delay {
+                Some(delay) => LARGE_SWAP_DELAY.save(deps.storage, &delay)?,
+                None => LARGE_SWAP_DELAY.remove(deps.storage),
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateLargeSwapThresholds { to_add, to_remove } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            for (asset, threshold) in to_add {
+                LARGE_SWAP_THRESHOLDS.save(deps.storage, &asset, &threshold)?;
+            }
+            for asset in to_remove {
+                LARGE_SWAP_THRESHOLDS.remove(deps.storage, &asset);
+            }
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateOutputValidation { enabled } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            OUTPUT_VALIDATION_ENABLED.save(deps.storage, &enabled)?;
+            Ok(Response::default())
+        }
+        DexExecuteMsg::UpdateDeprecatedDexes { to_add, to_remove } => {
+            assert_abstract_namespace_owner(deps.as_ref(), &adapter)?;
+
+            for dex in to_add {
+                // make sure the dex is known
+                exchange_resolver::identify_exchange(&dex)?;
+                DEPRECATED_DEXES.save(deps.storage, &dex, &())?;
+            }
+            for dex in to_remove {
+                DEPRECATED_DEXES.remove(deps.storage, &dex);
+            }
+            Ok(Response::default())
+        }
     }
 }
 
-/// Handle an adapter request that can be executed on the local chain
+/// Resolves an ANS action on the primary ANS host, falling back to the secondary ANS host if one is set.
+/// This is the only resolution that falls back to the secondary ANS host.
+pub(crate) fn resolve_ans_action(
+    deps: Deps,
+    adapter: &DexAdapter,
+    action: &WholeDexAction,
+) -> DexResult<DexRawAction> {
+    let primary_err = match adapter.name_service(deps).query(action) {
+        Ok(raw_action) => return Ok(raw_action),
+        Err(e) => e,
+    };
+    let Some(secondary) = SECONDARY_ANS_HOST.may_load(deps.storage)? else {
+        return Err(primary_err.into());
+    };
+    action
+        .resolve(&deps.querier, &secondary)
+        .map_err(|secondary_err| DexError::AnsFallbackFailed {
+            primary: primary_err.to_string(),
+            secondary: secondary_err.to_string(),
+        })
+}
+
+/// Asserts that the adapter is called by the owner of the abstract namespace
+fn assert_abstract_namespace_owner(deps: Deps, adapter: &DexAdapter) -> DexResult<()> {
+    let namespace = adapter
+        .module_registry(deps)?
+        .query_namespace(Namespace::new(ABSTRACT_NAMESPACE)?)?;
+
+    // unwrap namespace, since it's unlikely to have unclaimed abstract namespace
+    let namespace_info = namespace.unwrap();
+    ensure_eq!(
+        namespace_info.account_base,
+        adapter.target_account.clone().unwrap(),
+        DexError::Unauthorized {}
+    );
+    Ok(())
+}
+
+/// Handle an adapter request that can be executed on the local chain,
+/// `scheduled` swaps are exempt from the large swap delay
 fn handle_local_request(
-    deps: DepsMut,
-    _env: Env,
-    _info: MessageInfo,
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
     adapter: &DexAdapter,
     exchange: String,
     action: DexRawAction,
+    scheduled: bool,
 ) -> DexResult {
-    let exchange = exchange_resolver::resolve_exchange(&exchange)?;
+    let deprecation_warning = DEPRECATED_DEXES
+        .has(deps.storage, &exchange)
+        .then(|| format!("{exchange} is deprecated, consider migrating to another dex"));
+    let fee_exempt = FEE_EXEMPT_CALLERS.has(deps.storage, &info.sender);
+    let keeper = KEEPERS.has(deps.storage, &info.sender);
+    if let DexRawAction::Swap {
+        offer_asset,
+        ask_asset,
+        ..
+    } = &action
+    {
+        if !scheduled {
+            assert_not_large_swap(deps.as_ref(), adapter, offer_asset)?;
+        }
+        // keepers and fee exempt callers aren't rate-limited
+        if !keeper && !fee_exempt {
+            enforce_swap_cooldown(deps.branch(), &env, adapter)?;
+        }
+        record_swap_volume(deps.branch(), &env, adapter, offer_asset)?;
+        invalidate_cached_quotes(deps.branch(), adapter, &exchange, offer_asset, ask_asset)?;
+    }
     let target_account = adapter.account_base(deps.as_ref())?;
-    let (msgs, _) = crate::adapter::DexAdapter::resolve_dex_action(
+    let pending_swap = pending_swap(
+        deps.as_ref(),
+        &exchange,
+        &target_account.proxy,
+        fee_exempt,
+        &action,
+    )?;
+    let exchange = exchange_resolver::resolve_exchange(&exchange)?;
+    let (msgs, reply_id) = crate::adapter::DexAdapter::resolve_dex_action(
         adapter,
         deps.as_ref(),
         target_account.proxy,
+        fee_exempt,
+        keeper,
         action,
         exchange,
     )?;
-    let proxy_msg = adapter
-        .executor(deps.as_ref())
-        .execute(msgs.into_iter().map(Into::into).collect())?;
-    Ok(Response::new().add_message(proxy_msg))
+    let actions = msgs.into_iter().map(Into::into).collect();
+    let mut response = match pending_swap {
+        // check the output of the swap once it went through
+        Some(pending_swap) => {
+            // a swap that re-enters the adapter would overwrite the pending swap of the outer one
+            ensure!(
+                !PENDING_SWAP.exists(deps.storage),
+                DexError::SwapInProgress {}
+            );
+            PENDING_SWAP.save(deps.storage, &pending_swap)?;
+            Response::new().add_submessage(adapter.executor(deps.as_ref()).execute_with_reply(
+                actions,
+                ReplyOn::Success,
+                reply_id,
+            )?)
+        }
+        None => Response::new().add_message(adapter.executor(deps.as_ref()).execute(actions)?),
+    };
+    if let Some(warning) = deprecation_warning {
+        response = response.add_attribute("deprecation_warning", warning);
+    }
+    Ok(response)
+}
+
+/// Captures the state needed to check the output of a swap in its reply,
+/// if output validation or the price feed is enabled or the output goes to another recipient
+fn pending_swap(
+    deps: Deps,
+    dex: &str,
+    proxy: &Addr,
+    fee_exempt: bool,
+    action: &DexRawAction,
+) -> DexResult<Option<PendingSwap>> {
+    let DexRawAction::Swap {
+        offer_asset,
+        ask_asset,
+        output_recipient,
+        ..
+    } = action
+    else {
+        return Ok(None);
+    };
+    let output_recipient = output_recipient
+        .as_deref()
+        .map(|recipient| deps.api.addr_validate(recipient))
+        .transpose()?;
+    let offer_asset = offer_asset.check(deps.api, None)?;
+    let ask_asset = ask_asset.check(deps.api, None)?;
+    // the price is executed on the amount that is actually offered to the dex
+    let fee_amount = if fee_exempt {
+        Uint128::zero()
+    } else {
+        DEX_FEES
+            .load(deps.storage)?
+            .swap_fee()
+            .compute(offer_asset.amount)
+    };
+    let offer_amount = offer_asset.amount - fee_amount;
+
+    let price_feed = PRICE_FEED_ENABLED
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let output_validation = OUTPUT_VALIDATION_ENABLED
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if !price_feed && !output_validation && output_recipient.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(PendingSwap {
+        dex: dex.to_owned(),
+        proxy: proxy.clone(),
+        ask_balance: ask_asset.query_balance(&deps.querier, proxy)?,
+        offer_asset: offer_asset.info,
+        ask_asset,
+        offer_amount,
+        output_recipient,
+    }))
+}
+
+/// Records the time of the swap of the account, rejecting swaps within the cooldown of its last swap
+fn enforce_swap_cooldown(deps: DepsMut, env: &Env, adapter: &DexAdapter) -> DexResult<()> {
+    let Some(cooldown) = SWAP_COOLDOWN.may_load(deps.storage)? else {
+        return Ok(());
+    };
+    let account_id = adapter.account_id(deps.as_ref())?;
+    if let Some(last_swap) = LAST_SWAPS.may_load(deps.storage, &account_id)? {
+        let available_at = last_swap + cooldown;
+        ensure!(
+            env.block.time.seconds() >= available_at,
+            DexError::SwapCooldownActive { available_at }
+        );
+    }
+    LAST_SWAPS.save(deps.storage, &account_id, &env.block.time.seconds())?;
+    Ok(())
+}
+
+/// Asserts that the swap doesn't offer more than the large swap threshold of its offer asset,
+/// large swaps have to be scheduled ahead of their execution
+pub(crate) fn assert_not_large_swap(
+    deps: Deps,
+    adapter: &DexAdapter,
+    offer_asset: &AssetBase<String>,
+) -> DexResult<()> {
+    // skip the ans lookup while large swaps aren't delayed
+    if !LARGE_SWAP_DELAY.exists(deps.storage) || LARGE_SWAP_THRESHOLDS.is_empty(deps.storage) {
+        return Ok(());
+    }
+    let ans_host = adapter.ans_host(deps)?;
+    // Assets that are not registered on the ans host have no threshold
+    let asset = match ans_host
+        .query_asset_reverse(&deps.querier, &offer_asset.info.check(deps.api, None)?)
+    {
+        Ok(entry) => entry,
+        Err(AnsHostError::CwAssetNotFound { .. }) => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    if let Some(threshold) = LARGE_SWAP_THRESHOLDS.may_load(deps.storage, &asset)? {
+        ensure!(
+            offer_asset.amount <= threshold,
+            DexError::SwapMustBeScheduled {
+                offer_amount: offer_asset.amount,
+                threshold,
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Adds the offered amount to the volume of the current window, rejecting swaps over the cap of the offer asset
+fn record_swap_volume(
+    deps: DepsMut,
+    env: &Env,
+    adapter: &DexAdapter,
+    offer_asset: &AssetBase<String>,
+) -> DexResult<()> {
+    // skip the ans lookup while no asset is capped
+    if VOLUME_CAPS.is_empty(deps.storage) {
+        return Ok(());
+    }
+    let ans_host = adapter.ans_host(deps.as_ref())?;
+    // Assets that are not registered on the ans host have no cap
+    let asset = match ans_host
+        .query_asset_reverse(&deps.querier, &offer_asset.info.check(deps.api, None)?)
+    {
+        Ok(entry) => entry,
+        Err(AnsHostError::CwAssetNotFound { .. }) => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    let Some(volume_cap) = VOLUME_CAPS.may_load(deps.storage, &asset)? else {
+        return Ok(());
+    };
+    let mut window_volume = SWAP_VOLUMES
+        .may_load(deps.storage, &asset)?
+        // start a new window if the previous one ended
+        .filter(|w| env.block.time < w.window_start.plus_seconds(volume_cap.window))
+        .unwrap_or(WindowVolume {
+            volume: Uint128::zero(),
+            window_start: env.block.time,
+        });
+    let volume = window_volume
+        .volume
+        .checked_add(offer_asset.amount)
+        .map_err(StdError::from)?;
+    ensure!(
+        volume <= volume_cap.max_volume,
+        DexError::GlobalVolumeCapExceeded {
+            asset: asset.to_string(),
+            offer_amount: offer_asset.amount,
+            volume: window_volume.volume,
+            max_volume: volume_cap.max_volume,
+        }
+    );
+    window_volume.volume = volume;
+    SWAP_VOLUMES.save(deps.storage, &asset, &window_volume)?;
+    Ok(())
+}
+
+/// Drops the cached quotes of both directions of the swapped pair, as the swap moves the pool
+fn invalidate_cached_quotes(
+    deps: DepsMut,
+    adapter: &DexAdapter,
+    dex: &str,
+    offer_asset: &AssetBase<String>,
+    ask_asset: &AssetInfoBase<String>,
+) -> DexResult<()> {
+    // skip the ans lookups while nothing is cached
+    if !QUOTE_CACHE_TTL.exists(deps.storage) || QUOTE_CACHE.is_empty(deps.storage) {
+        return Ok(());
+    }
+    let ans_host = adapter.ans_host(deps.as_ref())?;
+    let mut entries = Vec::with_capacity(2);
+    for info in [
+        offer_asset.info.check(deps.api, None)?,
+        ask_asset.check(deps.api, None)?,
+    ] {
+        // Quotes can only be cached for assets registered on the ans host
+        match ans_host.query_asset_reverse(&deps.querier, &info) {
+            Ok(entry) => entries.push(entry),
+            Err(AnsHostError::CwAssetNotFound { .. }) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        }
+    }
+    for pairing in [
+        DexAssetPairing::new(entries[0].clone(), entries[1].clone(), dex),
+        DexAssetPairing::new(entries[1].clone(), entries[0].clone(), dex),
+    ] {
+        let amounts = QUOTE_CACHE
+            .prefix(&pairing)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<u128>>>()?;
+        for amount in amounts {
+            QUOTE_CACHE.remove(deps.storage, (&pairing, amount));
+        }
+    }
+    Ok(())
 }
 
 /// Handle an adapter request that can be executed on an IBC chain
@@ -1,19 +1,23 @@
 use abstract_adapter::sdk::features::AbstractNameService;
-use abstract_adapter::std::objects::{AssetEntry, DexAssetPairing, PoolAddress};
+use abstract_adapter::std::objects::{AnsAsset, AssetEntry, DexAssetPairing, PoolAddress};
 use abstract_dex_standard::{
     ans_action::{pool_address, WholeDexAction},
     msg::{
-        DexExecuteMsg, DexFeesResponse, DexQueryMsg, GenerateMessagesResponse, SimulateSwapResponse,
+        DexExecuteMsg, DexFeesResponse, DexQueryMsg, DexRawAction, GenerateMessagesResponse,
+        PriceFeedResponse, PriceImpactResponse, SimulateRouteResponse, SimulateSwapResponse,
     },
     DexError,
 };
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, StdError};
+use cosmwasm_std::{ensure, to_json_binary, Binary, Decimal, Deps, Env, StdError, Uint128};
 
 use crate::{
     contract::{DexAdapter, DexResult},
     exchanges::exchange_resolver::{self, resolve_exchange},
-    handlers::query::exchange_resolver::is_over_ibc,
-    state::DEX_FEES,
+    handlers::{
+        execute::{assert_not_large_swap, resolve_ans_action},
+        query::exchange_resolver::is_over_ibc,
+    },
+    state::{DEX_FEES, PRICE_FEED, QUOTE_CACHE, QUOTE_CACHE_TTL},
 };
 use cw_asset::{Asset, AssetInfo, AssetInfoBase};
 
@@ -46,11 +50,10 @@ pub fn query_handler(
             addr_as_sender,
         } => {
             if let DexExecuteMsg::AnsAction { dex, action } = message {
-                let ans = adapter.name_service(deps);
                 let whole_dex_action = WholeDexAction(dex.clone(), action);
                 message = DexExecuteMsg::RawAction {
                     dex,
-                    action: ans.query(&whole_dex_action)?,
+                    action: resolve_ans_action(deps, adapter, &whole_dex_action)?,
                 }
             }
             match message {
@@ -60,12 +63,28 @@ pub fn query_handler(
                     if is_over_ibc {
                         return Err(DexError::IbcMsgQuery);
                     }
+                    if let DexRawAction::Swap {
+                        offer_asset,
+                        output_recipient,
+                        ..
+                    } = &action
+                    {
+                        // the output is only forwarded when the adapter executes the swap
+                        ensure!(
+                            output_recipient.is_none(),
+                            DexError::OutputRecipientNotSupported {}
+                        );
+                        // large swaps have to go through the schedule
+                        assert_not_large_swap(deps, adapter, offer_asset)?;
+                    }
                     let exchange = exchange_resolver::resolve_exchange(&local_dex_name)?;
                     let addr_as_sender = deps.api.addr_validate(&addr_as_sender)?;
                     let (messages, _) = crate::adapter::DexAdapter::resolve_dex_action(
                         adapter,
                         deps,
                         addr_as_sender,
+                        false,
+                        false,
                         action,
                         exchange,
                     )?;
@@ -79,6 +98,45 @@ pub fn query_handler(
             offer_asset,
             ask_asset,
             dex,
+        } => {
+            // serve a cached quote while it's fresh
+            if let Some(ttl) = QUOTE_CACHE_TTL.may_load(deps.storage)? {
+                let (local_dex_name, _) = is_over_ibc(env.clone(), &dex)?;
+                let pairing = DexAssetPairing::new(
+                    offer_asset.name.clone(),
+                    ask_asset.clone(),
+                    &local_dex_name,
+                );
+                let cached =
+                    QUOTE_CACHE.may_load(deps.storage, (&pairing, offer_asset.amount.u128()))?;
+                if let Some(cached) = cached {
+                    if env.block.time < cached.cached_at.plus_seconds(ttl) {
+                        return to_json_binary(&cached.quote).map_err(Into::into);
+                    }
+                }
+            }
+            let resp = simulate_ans_swap(deps, env, adapter, offer_asset, ask_asset, dex)?;
+            to_json_binary(&resp).map_err(Into::into)
+        }
+        DexQueryMsg::PriceFeed {
+            offer_asset,
+            ask_asset,
+            dex,
+        } => {
+            // prices are recorded under the local dex name
+            let (local_dex_name, _) = is_over_ibc(env, &dex)?;
+            let ans = adapter.name_service(deps);
+            let offer_asset = ans.query(&offer_asset)?.to_string();
+            let ask_asset = ans.query(&ask_asset)?.to_string();
+            let price =
+                PRICE_FEED.may_load(deps.storage, (&local_dex_name, &offer_asset, &ask_asset))?;
+            to_json_binary(&PriceFeedResponse { price }).map_err(Into::into)
+        }
+        DexQueryMsg::PriceImpact {
+            offer_asset,
+            ask_asset,
+            amount,
+            dex,
         } => {
             let ans = adapter.name_service(deps);
             let cw_offer_asset = ans.query(&offer_asset)?;
@@ -86,32 +144,26 @@ pub fn query_handler(
 
             let pool_address = pool_address(
                 dex.clone(),
-                (offer_asset.name.clone(), ask_asset.clone()),
+                (offer_asset, ask_asset),
                 &deps.querier,
                 ans.host(),
             )?;
 
-            let simulate_response = simulate_swap(
+            let resp = price_impact(
                 deps,
-                env,
-                dex.clone(),
+                dex,
                 pool_address,
-                cw_offer_asset,
-                cw_ask_asset.clone(),
+                Asset::new(cw_offer_asset, amount),
+                cw_ask_asset,
             )?;
-
-            // We return ans assets here
-            let resp = SimulateSwapResponse::<AssetEntry> {
-                pool: DexAssetPairing::new(offer_asset.name.clone(), ask_asset.clone(), &dex),
-                return_amount: simulate_response.return_amount,
-                spread_amount: simulate_response.spread_amount,
-                commission: if simulate_response.commission.0 == cw_ask_asset.into() {
-                    (ask_asset, simulate_response.commission.1)
-                } else {
-                    (offer_asset.name, simulate_response.commission.1)
-                },
-                usage_fee: simulate_response.usage_fee,
-            };
+            to_json_binary(&resp).map_err(Into::into)
+        }
+        DexQueryMsg::SimulateRoute {
+            offer_asset,
+            route,
+            amount,
+        } => {
+            let resp = simulate_route(deps, adapter, offer_asset, route, amount)?;
             to_json_binary(&resp).map_err(Into::into)
         }
     }
@@ -126,6 +178,49 @@ pub fn fees(deps: Deps) -> DexResult<Binary> {
     to_json_binary(&resp).map_err(Into::into)
 }
 
+/// Simulates a swap between ans assets, returning the ans assets of the swap
+pub fn simulate_ans_swap(
+    deps: Deps,
+    env: Env,
+    adapter: &DexAdapter,
+    offer_asset: AnsAsset,
+    ask_asset: AssetEntry,
+    dex: String,
+) -> DexResult<SimulateSwapResponse> {
+    let ans = adapter.name_service(deps);
+    let cw_offer_asset = ans.query(&offer_asset)?;
+    let cw_ask_asset = ans.query(&ask_asset)?;
+
+    let pool_address = pool_address(
+        dex.clone(),
+        (offer_asset.name.clone(), ask_asset.clone()),
+        &deps.querier,
+        ans.host(),
+    )?;
+
+    let simulate_response = simulate_swap(
+        deps,
+        env,
+        dex.clone(),
+        pool_address,
+        cw_offer_asset,
+        cw_ask_asset.clone(),
+    )?;
+
+    // We return ans assets here
+    Ok(SimulateSwapResponse::<AssetEntry> {
+        pool: DexAssetPairing::new(offer_asset.name.clone(), ask_asset.clone(), &dex),
+        return_amount: simulate_response.return_amount,
+        spread_amount: simulate_response.spread_amount,
+        commission: if simulate_response.commission.0 == cw_ask_asset.into() {
+            (ask_asset, simulate_response.commission.1)
+        } else {
+            (offer_asset.name, simulate_response.commission.1)
+        },
+        usage_fee: simulate_response.usage_fee,
+    })
+}
+
 pub fn simulate_swap(
     deps: Deps,
     _env: Env,
@@ -165,3 +260,96 @@ pub fn simulate_swap(
     };
     Ok(resp)
 }
+
+pub fn price_impact(
+    deps: Deps,
+    dex: String,
+    pool: PoolAddress,
+    mut offer_asset: Asset,
+    ask_asset: AssetInfo,
+) -> DexResult<PriceImpactResponse> {
+    let exchange = resolve_exchange(&dex)?;
+
+    // the adapter fee is deducted before the swap, so only the remainder moves the pool
+    let dex_fees = DEX_FEES.load(deps.storage)?;
+    offer_asset.amount -= dex_fees.swap_fee().compute(offer_asset.amount);
+
+    let (return_amount, spread_amount, commission_amount, fee_on_input) =
+        exchange.simulate_swap(deps, pool, offer_asset.clone(), ask_asset)?;
+
+    // commission withheld from the output is part of the traded amount
+    let swap_output = if fee_on_input {
+        return_amount
+    } else {
+        return_amount + commission_amount
+    };
+    let spot_output = swap_output + spread_amount;
+
+    let ratio = |numerator: Uint128, denominator: Uint128| {
+        Decimal::checked_from_ratio(numerator, denominator)
+            .map_err(|e| StdError::generic_err(e.to_string()))
+    };
+    Ok(PriceImpactResponse {
+        pre_swap_price: ratio(spot_output, offer_asset.amount)?,
+        post_swap_price: ratio(swap_output, offer_asset.amount)?,
+        price_impact: ratio(spread_amount, spot_output)?,
+    })
+}
+
+pub fn simulate_route(
+    deps: Deps,
+    adapter: &DexAdapter,
+    offer_asset: AssetEntry,
+    route: Vec<DexAssetPairing>,
+    amount: Uint128,
+) -> DexResult<SimulateRouteResponse> {
+    if route.is_empty() {
+        return Err(DexError::EmptyRoute {});
+    }
+    // every hop has to offer the asset received from the previous hop
+    let mut expected = &offer_asset;
+    for (hop, pairing) in route.iter().enumerate() {
+        if pairing.asset_x() != expected {
+            return Err(DexError::NonContiguousRoute {
+                hop,
+                offer_asset: pairing.asset_x().clone(),
+                expected: expected.clone(),
+            });
+        }
+        expected = pairing.asset_y();
+    }
+
+    // the adapter fee is only charged once, on the input of the route
+    let dex_fees = DEX_FEES.load(deps.storage)?;
+    let usage_fee = dex_fees.swap_fee().compute(amount);
+    let mut hop_amount = amount - usage_fee;
+
+    let ans = adapter.name_service(deps);
+    let mut hop_amounts = Vec::with_capacity(route.len());
+    for pairing in route {
+        let exchange = resolve_exchange(pairing.dex())?;
+        let cw_offer_asset = ans.query(pairing.asset_x())?;
+        let cw_ask_asset = ans.query(pairing.asset_y())?;
+        let pool_address = pool_address(
+            pairing.dex().to_owned(),
+            (pairing.asset_x().clone(), pairing.asset_y().clone()),
+            &deps.querier,
+            ans.host(),
+        )?;
+
+        let (return_amount, ..) = exchange.simulate_swap(
+            deps,
+            pool_address,
+            Asset::new(cw_offer_asset, hop_amount),
+            cw_ask_asset,
+        )?;
+        hop_amount = return_amount;
+        hop_amounts.push(AnsAsset::new(pairing.asset_y().clone(), return_amount));
+    }
+
+    Ok(SimulateRouteResponse {
+        return_amount: hop_amount,
+        hop_amounts,
+        usage_fee,
+    })
+}
@@ -0,0 +1,76 @@
+use abstract_adapter::std::proxy::ExecuteMsg as ProxyExecuteMsg;
+use abstract_dex_standard::{msg::RecordedPrice, DexError};
+use cosmwasm_std::{ensure, wasm_execute, Decimal, DepsMut, Env, Reply, Response, StdError};
+use cw_asset::Asset;
+
+use crate::{
+    contract::{DexAdapter, DexResult},
+    state::{OUTPUT_VALIDATION_ENABLED, PENDING_SWAP, PRICE_FEED, PRICE_FEED_ENABLED},
+};
+
+/// Validates the output of a swap, forwards it to its recipient and records its executed price,
+/// from the ask asset received by the proxy
+pub fn swap_reply(deps: DepsMut, env: Env, _adapter: DexAdapter, _reply: Reply) -> DexResult {
+    let pending_swap = PENDING_SWAP.load(deps.storage)?;
+    PENDING_SWAP.remove(deps.storage);
+
+    let ask_balance = pending_swap
+        .ask_asset
+        .query_balance(&deps.querier, &pending_swap.proxy)?;
+    let return_amount = ask_balance
+        .checked_sub(pending_swap.ask_balance)
+        .map_err(StdError::from)?;
+
+    // a swap that returned none of the ask asset returned some other asset
+    if OUTPUT_VALIDATION_ENABLED
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+    {
+        ensure!(
+            !return_amount.is_zero(),
+            DexError::UnexpectedOutputAsset {
+                expected: pending_swap.ask_asset.to_string()
+            }
+        );
+    }
+
+    let mut response = Response::new();
+    // send the output to its recipient
+    if let Some(recipient) = pending_swap
+        .output_recipient
+        .filter(|_| !return_amount.is_zero())
+    {
+        let transfer =
+            Asset::new(pending_swap.ask_asset.clone(), return_amount).transfer_msg(&recipient)?;
+        response = response
+            .add_message(wasm_execute(
+                pending_swap.proxy.to_string(),
+                &ProxyExecuteMsg::ModuleAction {
+                    msgs: vec![transfer],
+                },
+                vec![],
+            )?)
+            .add_attribute("output_recipient", recipient);
+    }
+    if PRICE_FEED_ENABLED
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+    {
+        let price = Decimal::checked_from_ratio(return_amount, pending_swap.offer_amount)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        PRICE_FEED.save(
+            deps.storage,
+            (
+                &pending_swap.dex,
+                &pending_swap.offer_asset.to_string(),
+                &pending_swap.ask_asset.to_string(),
+            ),
+            &RecordedPrice {
+                price,
+                recorded_at: env.block.time,
+            },
+        )?;
+        response = response.add_attribute("executed_price", price.to_string());
+    }
+    Ok(response)
+}
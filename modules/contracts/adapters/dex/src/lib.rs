@@ -108,6 +108,9 @@ pub mod interface {
                 ask_asset,
                 max_spread: Some(Decimal::percent(30)),
                 belief_price: None,
+                min_receive: None,
+                bypass_slippage: false,
+                output_recipient: None,
             };
             self.ans_action(dex, action, account)?;
             Ok(())
@@ -128,6 +131,9 @@ pub mod interface {
                 pool,
                 max_spread: Some(Decimal::percent(30)),
                 belief_price: None,
+                min_receive: None,
+                bypass_slippage: false,
+                output_recipient: None,
             };
             self.raw_action(dex, action, account)?;
             Ok(())
@@ -1,4 +1,95 @@
-use abstract_dex_standard::msg::DexFees;
-use cw_storage_plus::Item;
+use abstract_adapter::std::objects::{ans_host::AnsHost, AccountId, AssetEntry, DexAssetPairing};
+use abstract_dex_standard::{
+    msg::{AssetListMode, DexFees, RecordedPrice, SimulateSwapResponse, VolumeCap},
+    raw_action::DexRawAction,
+};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw_asset::AssetInfo;
+use cw_storage_plus::{Item, Map};
 
 pub const DEX_FEES: Item<DexFees> = Item::new("dex_fees");
+/// Callers that don't pay the swap fee
+pub const FEE_EXEMPT_CALLERS: Map<&Addr, ()> = Map::new("fee_exempt_callers");
+/// Callers that can bypass the slippage protection of their swaps
+pub const KEEPERS: Map<&Addr, ()> = Map::new("keepers");
+/// Dexes that are deprecated and emit a warning when used
+pub const DEPRECATED_DEXES: Map<&str, ()> = Map::new("deprecated_dexes");
+/// Router contracts that swaps on a dex are executed through
+pub const ROUTERS: Map<&str, Addr> = Map::new("routers");
+/// Minimum liquidity a pool must hold of an asset to swap it
+pub const MIN_POOL_LIQUIDITY: Map<&AssetEntry, Uint128> = Map::new("min_pool_liquidity");
+/// Maximum share of the offer asset reserves of a pool a single swap may offer
+pub const MAX_POOL_SHARE: Item<Decimal> = Item::new("max_pool_share");
+/// Cap on the volume of an offer asset swapped within a window, assets without a cap are uncapped
+pub const VOLUME_CAPS: Map<&AssetEntry, VolumeCap> = Map::new("volume_caps");
+/// Volume of a capped offer asset swapped in the current window
+pub const SWAP_VOLUMES: Map<&AssetEntry, WindowVolume> = Map::new("swap_volumes");
+/// ANS host consulted when the primary ANS host fails to resolve an ANS action.
+/// Only the resolution of ANS actions falls back to it, all other lookups use the primary ANS host.
+pub const SECONDARY_ANS_HOST: Item<AnsHost> = Item::new("secondary_ans_host");
+/// How the asset lists restrict the swapped assets, unrestricted if unset
+pub const ASSET_LIST_MODE: Item<AssetListMode> = Item::new("asset_list_mode");
+/// Assets that can be swapped in allowlist mode
+pub const ALLOWED_ASSETS: Map<&AssetEntry, ()> = Map::new("allowed_assets");
+/// Assets that can't be swapped in blocklist mode
+pub const BLOCKED_ASSETS: Map<&AssetEntry, ()> = Map::new("blocked_assets");
+/// How long cached quotes are served in seconds, caching is disabled if unset
+pub const QUOTE_CACHE_TTL: Item<u64> = Item::new("quote_cache_ttl");
+/// Cached swap simulations, keyed by pairing and offered amount
+pub const QUOTE_CACHE: Map<(&DexAssetPairing, u128), CachedQuote> = Map::new("quote_cache");
+/// Maximum number of quotes the cache holds, which bounds the work of invalidating or clearing it
+pub const MAX_CACHED_QUOTES: usize = 100;
+/// Whether the executed price of swaps is recorded
+pub const PRICE_FEED_ENABLED: Item<bool> = Item::new("price_feed_enabled");
+/// Price executed by the last swap, keyed by dex, offer asset and ask asset
+pub const PRICE_FEED: Map<(&str, &str, &str), RecordedPrice> = Map::new("price_feed");
+/// Whether swaps that return none of the ask asset are reverted
+pub const OUTPUT_VALIDATION_ENABLED: Item<bool> = Item::new("output_validation_enabled");
+/// Minimum time in seconds between the swaps of an account, unlimited if unset
+pub const SWAP_COOLDOWN: Item<u64> = Item::new("swap_cooldown");
+/// Time in seconds of the last swap of each account
+pub const LAST_SWAPS: Map<&AccountId, u64> = Map::new("last_swaps");
+/// Number of blocks between scheduling a large swap and its execution, large swaps execute immediately if unset
+pub const LARGE_SWAP_DELAY: Item<u64> = Item::new("large_swap_delay");
+/// Offer amount of an asset above which its swaps are large, assets without a threshold are never delayed
+pub const LARGE_SWAP_THRESHOLDS: Map<&AssetEntry, Uint128> = Map::new("large_swap_thresholds");
+/// Id of the next scheduled swap
+pub const NEXT_SCHEDULED_SWAP_ID: Item<u64> = Item::new("next_scheduled_swap_id");
+/// Swaps scheduled by accounts, keyed by proxy and swap id
+pub const SCHEDULED_SWAPS: Map<(&Addr, u64), ScheduledSwap> = Map::new("scheduled_swaps");
+/// Swap awaiting its reply to validate its output and record the executed price
+pub const PENDING_SWAP: Item<PendingSwap> = Item::new("pending_swap");
+
+#[cosmwasm_schema::cw_serde]
+pub struct CachedQuote {
+    pub quote: SimulateSwapResponse,
+    pub cached_at: Timestamp,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct WindowVolume {
+    pub volume: Uint128,
+    pub window_start: Timestamp,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PendingSwap {
+    pub dex: String,
+    pub proxy: Addr,
+    pub offer_asset: AssetInfo,
+    pub ask_asset: AssetInfo,
+    /// Amount offered to the dex, after the adapter fee
+    pub offer_amount: Uint128,
+    /// Balance of the ask asset held by the proxy before the swap
+    pub ask_balance: Uint128,
+    /// Address the output of the swap is sent to, if not the account
+    pub output_recipient: Option<Addr>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ScheduledSwap {
+    pub dex: String,
+    pub action: DexRawAction,
+    /// Block height from which the swap can be executed
+    pub execute_after: u64,
+}
@@ -1,5 +1,13 @@
-use abstract_adapter::std::objects::gov_type::GovernanceDetails;
-use abstract_interface::{AbstractAccount, AccountFactory};
+use abstract_adapter::std::{
+    adapter::AdapterRequestMsg,
+    objects::{gov_type::GovernanceDetails, ABSTRACT_ACCOUNT_ID},
+};
+use abstract_dex_adapter::interface::DexAdapter;
+use abstract_dex_standard::{
+    msg::{DexExecuteMsg, ExecuteMsg},
+    DexError,
+};
+use abstract_interface::{Abstract, AbstractAccount, AbstractInterfaceError, AccountFactory};
 use cw_orch::prelude::*;
 pub fn create_default_account<Chain: CwEnv>(
     factory: &AccountFactory<Chain>,
@@ -10,6 +18,32 @@ pub fn create_default_account<Chain: CwEnv>(
     Ok(os)
 }
 
+/// Executes a request on the dex adapter as the abstract account, which owns the abstract namespace
+#[allow(dead_code)]
+pub fn admin_request<Chain: CwEnv>(
+    dex_adapter: &DexAdapter<Chain>,
+    abstr: &Abstract<Chain>,
+    request: DexExecuteMsg,
+) -> Result<Chain::Response, CwOrchError> {
+    let account0 = AbstractAccount::new(abstr, ABSTRACT_ACCOUNT_ID);
+    dex_adapter.execute(
+        &ExecuteMsg::Module(AdapterRequestMsg {
+            proxy_address: Some(account0.proxy.addr_str()?),
+            request,
+        }),
+        None,
+    )
+}
+
+/// Returns the [`DexError`] a call to the dex adapter failed with
+#[allow(dead_code)]
+pub fn dex_err(err: impl Into<AbstractInterfaceError>) -> DexError {
+    let AbstractInterfaceError::Orch(orch_error) = err.into() else {
+        panic!("unexpected error type");
+    };
+    orch_error.downcast().unwrap()
+}
+
 // /// Instantiates the dex adapter and registers it with the version control
 // #[allow(dead_code)]
 // pub fn init_dex_adapter(
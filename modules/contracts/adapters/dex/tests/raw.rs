@@ -4,7 +4,7 @@ use abstract_adapter::std::{
     objects::{PoolAddress, ABSTRACT_ACCOUNT_ID},
 };
 use abstract_dex_adapter::{contract::CONTRACT_VERSION, msg::DexInstantiateMsg, DEX_ADAPTER_ID};
-use abstract_dex_standard::msg::DexExecuteMsg;
+use abstract_dex_standard::{msg::DexExecuteMsg, DexError};
 use abstract_interface::{AdapterDeployer, DeployStrategy};
 use cw20::msg::Cw20ExecuteMsgFns as _;
 use cw20_base::msg::QueryMsgFns as _;
@@ -13,11 +13,11 @@ mod common;
 use abstract_dex_adapter::interface::DexAdapter;
 use abstract_dex_standard::raw_action::DexRawAction;
 use abstract_interface::{Abstract, AbstractAccount};
-use common::create_default_account;
+use common::{admin_request, create_default_account, dex_err};
 use cosmwasm_std::{coin, Decimal};
 use cw_orch::prelude::*;
 use speculoos::*;
-use wyndex_bundle::{EUR, USD, WYNDEX as WYNDEX_WITHOUT_CHAIN, WYNDEX_OWNER};
+use wyndex_bundle::{EUR, USD, WYNDEX as WYNDEX_WITHOUT_CHAIN, WYNDEX_OWNER, WYND_TOKEN};
 
 const WYNDEX: &str = "cosmos-testnet>wyndex";
 
@@ -148,6 +148,9 @@ fn raw_swap_raw() -> anyhow::Result<()> {
                 pool: PoolAddress::contract(wyndex.raw_eur_pair).into(),
                 max_spread: Some(Decimal::percent(30)),
                 belief_price: None,
+                min_receive: None,
+                bypass_slippage: false,
+                output_recipient: None,
             },
         },
     });
@@ -169,3 +172,47 @@ fn raw_swap_raw() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn output_validation() -> anyhow::Result<()> {
+    let (chain, wyndex, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateOutputValidation { enabled: true },
+    )?;
+
+    // A correctly routed swap goes through
+    dex_adapter.raw_swap_native(
+        (EUR, 100),
+        USD,
+        WYNDEX.into(),
+        &os,
+        PoolAddress::contract(wyndex.eur_usd_pair.clone()).into(),
+    )?;
+    let usd_balance = chain.query_balance(&proxy_addr, USD)?;
+    assert_that!(usd_balance.u128()).is_equal_to(98);
+
+    // Asking for WYND on the EUR/USD pool returns USD, so the swap is reverted
+    let err = dex_adapter
+        .raw_swap_native(
+            (EUR, 100),
+            WYND_TOKEN,
+            WYNDEX.into(),
+            &os,
+            PoolAddress::contract(wyndex.eur_usd_pair).into(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        dex_err(err),
+        DexError::UnexpectedOutputAsset {
+            expected: format!("native:{WYND_TOKEN}")
+        }
+    );
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_900);
+
+    Ok(())
+}
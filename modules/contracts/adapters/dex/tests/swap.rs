@@ -1,15 +1,28 @@
-use abstract_adapter::std::{ans_host::QueryMsgFns as _, objects::ABSTRACT_ACCOUNT_ID};
+use abstract_adapter::std::{
+    ans_host::{ExecuteMsgFns as _, QueryMsgFns as _},
+    objects::{
+        pool_id::PoolAddressBase, AnsAsset, AssetEntry, DexAssetPairing, PoolMetadata,
+        ABSTRACT_ACCOUNT_ID,
+    },
+};
 use abstract_dex_adapter::{contract::CONTRACT_VERSION, msg::DexInstantiateMsg, DEX_ADAPTER_ID};
-use abstract_dex_standard::{msg::DexFeesResponse, DexError};
-use abstract_interface::{AbstractInterfaceError, AdapterDeployer, DeployStrategy};
+use abstract_dex_standard::{
+    msg::{
+        AssetListMode, DexAnsAction, DexExecuteMsg, DexFeesResponse, DexQueryMsg,
+        GenerateMessagesResponse, PriceFeedResponse, PriceImpactResponse, SimulateRouteResponse,
+        SimulateSwapResponse, VolumeCap,
+    },
+    DexError,
+};
+use abstract_interface::{AdapterDeployer, DeployStrategy};
 use cw20::msg::Cw20ExecuteMsgFns as _;
 use cw20_base::msg::QueryMsgFns as _;
 mod common;
 
 use abstract_dex_adapter::interface::DexAdapter;
 use abstract_interface::{Abstract, AbstractAccount};
-use common::create_default_account;
-use cosmwasm_std::{coin, Decimal};
+use common::{admin_request, create_default_account, dex_err};
+use cosmwasm_std::{coin, CosmosMsg, Decimal, Uint128, WasmMsg};
 use cw_orch::prelude::*;
 use speculoos::*;
 use wyndex_bundle::{EUR, RAW_TOKEN, USD, WYNDEX as WYNDEX_WITHOUT_CHAIN, WYNDEX_OWNER};
@@ -106,6 +119,142 @@ fn swap_native_without_chain() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn swap_with_max_spread() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, _) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    // swap 100 EUR to USD with a spread tolerance
+    dex_adapter.ans_action(
+        WYNDEX.into(),
+        DexAnsAction::Swap {
+            offer_asset: AnsAsset::new(EUR, 100u128),
+            ask_asset: AssetEntry::new(USD),
+            max_spread: Some(Decimal::percent(30)),
+            belief_price: None,
+            min_receive: None,
+            bypass_slippage: false,
+            output_recipient: None,
+        },
+        &os,
+    )?;
+
+    let usd_balance = chain.query_balance(&proxy_addr, USD)?;
+    assert_that!(usd_balance.u128()).is_equal_to(98);
+
+    Ok(())
+}
+
+#[test]
+fn swap_with_min_receive() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, _) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    let swap_action = |min_receive: u128| DexAnsAction::Swap {
+        offer_asset: AnsAsset::new(EUR, 100u128),
+        ask_asset: AssetEntry::new(USD),
+        max_spread: None,
+        belief_price: None,
+        min_receive: Some(min_receive.into()),
+        bypass_slippage: false,
+        output_recipient: None,
+    };
+
+    // 100 EUR returns 98 USD, so requiring more must fail
+    let res = dex_adapter.ans_action(WYNDEX.into(), swap_action(99), &os);
+    assert_that!(res).is_err();
+
+    dex_adapter.ans_action(WYNDEX.into(), swap_action(98), &os)?;
+
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_900);
+    let usd_balance = chain.query_balance(&proxy_addr, USD)?;
+    assert_that!(usd_balance.u128()).is_equal_to(98);
+
+    Ok(())
+}
+
+#[test]
+fn swap_with_output_recipient() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+    let account0_proxy = AbstractAccount::new(&abstr, ABSTRACT_ACCOUNT_ID)
+        .proxy
+        .address()?;
+    let recipient = chain.addr_make("recipient");
+
+    let swap_action = |output_recipient: &str| DexAnsAction::Swap {
+        offer_asset: AnsAsset::new(EUR, 100u128),
+        ask_asset: AssetEntry::new(USD),
+        max_spread: None,
+        belief_price: None,
+        min_receive: None,
+        bypass_slippage: false,
+        output_recipient: Some(output_recipient.to_owned()),
+    };
+
+    // The recipient has to be a valid address
+    let res = dex_adapter.ans_action(WYNDEX.into(), swap_action("invalid"), &os);
+    assert_that!(res).is_err();
+
+    dex_adapter.ans_action(WYNDEX.into(), swap_action(recipient.as_str()), &os)?;
+
+    // The account pays the offer asset and the fee, the recipient receives the output
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_900);
+    let account0_eur_balance = chain.query_balance(&account0_proxy, EUR)?;
+    assert_that!(account0_eur_balance.u128()).is_equal_to(1);
+    let usd_balance = chain.query_balance(&proxy_addr, USD)?;
+    assert_that!(usd_balance.u128()).is_equal_to(0);
+    let recipient_usd_balance = chain.query_balance(&recipient, USD)?;
+    assert_that!(recipient_usd_balance.u128()).is_equal_to(98);
+
+    // Generated messages can't forward the output to the recipient
+    let msg = DexQueryMsg::GenerateMessages {
+        message: DexExecuteMsg::AnsAction {
+            dex: WYNDEX.into(),
+            action: swap_action(recipient.as_str()),
+        },
+        addr_as_sender: proxy_addr.to_string(),
+    };
+    let res: Result<GenerateMessagesResponse, _> = dex_adapter.query(&msg.into());
+    assert_that!(res.unwrap_err().to_string())
+        .contains("can't send the output of a swap to a recipient");
+
+    Ok(())
+}
+
+#[test]
+fn swap_with_conflicting_slippage_params() -> anyhow::Result<()> {
+    let (_, _, dex_adapter, os, _) = setup_mock()?;
+
+    // `min_receive` can't be combined with either `max_spread` or `belief_price`
+    let conflicting_params = [
+        (Some(Decimal::percent(30)), None),
+        (None, Some(Decimal::one())),
+    ];
+    for (max_spread, belief_price) in conflicting_params {
+        let err = dex_adapter
+            .ans_action(
+                WYNDEX.into(),
+                DexAnsAction::Swap {
+                    offer_asset: AnsAsset::new(EUR, 100u128),
+                    ask_asset: AssetEntry::new(USD),
+                    max_spread,
+                    belief_price,
+                    min_receive: Some(98u128.into()),
+                    bypass_slippage: false,
+                    output_recipient: None,
+                },
+                &os,
+            )
+            .unwrap_err();
+        assert_eq!(dex_err(err), DexError::ConflictingSlippageParams {});
+    }
+
+    Ok(())
+}
+
 #[test]
 fn swap_raw() -> anyhow::Result<()> {
     let (chain, wyndex, dex_adapter, os, abstr) = setup_mock()?;
@@ -138,6 +287,116 @@ fn swap_raw() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn price_impact() -> anyhow::Result<()> {
+    let (_, _, dex_adapter, _, _) = setup_mock()?;
+
+    let query_impact = |amount: u128| -> anyhow::Result<PriceImpactResponse> {
+        let msg = DexQueryMsg::PriceImpact {
+            offer_asset: AssetEntry::new(EUR),
+            ask_asset: AssetEntry::new(USD),
+            amount: amount.into(),
+            dex: WYNDEX_WITHOUT_CHAIN.into(),
+        };
+        Ok(dex_adapter.query(&msg.into())?)
+    };
+
+    // 1_000 EUR minus the 1% adapter fee is offered to the 10_000/10_000 EUR/USD pool
+    // so the expected impact is 990 / (10_000 + 990) ~= 9%.
+    let impact = query_impact(1_000)?;
+    assert_that!(impact.pre_swap_price).is_equal_to(Decimal::one());
+    assert_that!(impact.price_impact).is_greater_than(Decimal::permille(89));
+    assert_that!(impact.price_impact).is_less_than(Decimal::permille(92));
+    assert_that!(impact.post_swap_price).is_less_than(impact.pre_swap_price);
+
+    // larger swaps move the price more
+    let larger_impact = query_impact(2_000)?;
+    assert_that!(larger_impact.pre_swap_price).is_equal_to(Decimal::one());
+    assert_that!(larger_impact.price_impact).is_greater_than(impact.price_impact);
+
+    Ok(())
+}
+
+#[test]
+fn simulate_route() -> anyhow::Result<()> {
+    let (_, _, dex_adapter, _, _) = setup_mock()?;
+
+    // USD -> EUR -> RAW
+    let route = vec![
+        DexAssetPairing::new(
+            AssetEntry::new(USD),
+            AssetEntry::new(EUR),
+            WYNDEX_WITHOUT_CHAIN,
+        ),
+        DexAssetPairing::new(
+            AssetEntry::new(EUR),
+            AssetEntry::new(RAW_TOKEN),
+            WYNDEX_WITHOUT_CHAIN,
+        ),
+    ];
+    let msg = DexQueryMsg::SimulateRoute {
+        offer_asset: AssetEntry::new(USD),
+        route,
+        amount: 1_000u128.into(),
+    };
+    let resp: SimulateRouteResponse = dex_adapter.query(&msg.into())?;
+
+    // fee is charged once, on the input
+    assert_that!(resp.usage_fee.u128()).is_equal_to(10);
+    assert_that!(resp.hop_amounts).has_length(2);
+    assert_that!(resp.hop_amounts[0].name).is_equal_to(AssetEntry::new(EUR));
+    assert_that!(resp.hop_amounts[1].name).is_equal_to(AssetEntry::new(RAW_TOKEN));
+    assert_that!(resp.return_amount).is_equal_to(resp.hop_amounts[1].amount);
+
+    // first hop matches a single swap simulation
+    let msg = DexQueryMsg::SimulateSwap {
+        offer_asset: AnsAsset::new(USD, 1_000u128),
+        ask_asset: AssetEntry::new(EUR),
+        dex: WYNDEX_WITHOUT_CHAIN.into(),
+    };
+    let single_hop: SimulateSwapResponse = dex_adapter.query(&msg.into())?;
+    assert_that!(resp.hop_amounts[0].amount).is_equal_to(single_hop.return_amount);
+
+    Ok(())
+}
+
+#[test]
+fn simulate_non_contiguous_route() -> anyhow::Result<()> {
+    let (_, _, dex_adapter, _, _) = setup_mock()?;
+
+    // USD -> EUR, RAW -> EUR
+    let route = vec![
+        DexAssetPairing::new(
+            AssetEntry::new(USD),
+            AssetEntry::new(EUR),
+            WYNDEX_WITHOUT_CHAIN,
+        ),
+        DexAssetPairing::new(
+            AssetEntry::new(RAW_TOKEN),
+            AssetEntry::new(EUR),
+            WYNDEX_WITHOUT_CHAIN,
+        ),
+    ];
+    let msg = DexQueryMsg::SimulateRoute {
+        offer_asset: AssetEntry::new(USD),
+        route,
+        amount: 1_000u128.into(),
+    };
+    let err = dex_adapter
+        .query::<SimulateRouteResponse>(&msg.into())
+        .unwrap_err();
+    assert!(err.to_string().contains(
+        &DexError::NonContiguousRoute {
+            hop: 1,
+            offer_asset: AssetEntry::new(RAW_TOKEN),
+            expected: AssetEntry::new(EUR),
+        }
+        .to_string()
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn get_fees() -> anyhow::Result<()> {
     let (_, _, dex_adapter, _, abstr) = setup_mock()?;
@@ -156,19 +415,15 @@ fn get_fees() -> anyhow::Result<()> {
 #[test]
 fn authorized_update_fee() -> anyhow::Result<()> {
     let (_, _, dex_adapter, _, abstr) = setup_mock()?;
-    let account0 = AbstractAccount::new(&abstr, ABSTRACT_ACCOUNT_ID);
 
-    let update_fee_msg = abstract_dex_standard::msg::ExecuteMsg::Module(
-        abstract_adapter::std::adapter::AdapterRequestMsg {
-            proxy_address: Some(account0.proxy.addr_str()?),
-            request: abstract_dex_standard::msg::DexExecuteMsg::UpdateFee {
-                swap_fee: Some(Decimal::percent(5)),
-                recipient_account: None,
-            },
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFee {
+            swap_fee: Some(Decimal::percent(5)),
+            recipient_account: None,
         },
-    );
-
-    dex_adapter.execute(&update_fee_msg, None)?;
+    )?;
 
     use abstract_dex_adapter::msg::DexQueryMsgFns as _;
 
@@ -195,10 +450,969 @@ fn unauthorized_update_fee() -> anyhow::Result<()> {
         .manager
         .execute_on_module(DEX_ADAPTER_ID, update_fee_msg)
         .unwrap_err();
-    let AbstractInterfaceError::Orch(orch_error) = err else {
-        panic!("unexpected error type");
+    assert_eq!(dex_err(err), DexError::Unauthorized {});
+    Ok(())
+}
+
+#[test]
+fn fee_exempt_caller() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+    let account0_proxy = AbstractAccount::new(&abstr, ABSTRACT_ACCOUNT_ID)
+        .proxy
+        .address()?;
+
+    // Protocol contract that routes swaps for the account
+    let protocol_contract = chain.addr_make("protocol_contract");
+    os.manager.update_adapter_authorized_addresses(
+        DEX_ADAPTER_ID,
+        vec![protocol_contract.to_string()],
+        vec![],
+    )?;
+
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFeeExemptCallers {
+            to_add: vec![protocol_contract.to_string()],
+            to_remove: vec![],
+        },
+    )?;
+
+    let swap_msg = abstract_dex_standard::msg::ExecuteMsg::Module(
+        abstract_adapter::std::adapter::AdapterRequestMsg {
+            proxy_address: Some(proxy_addr.to_string()),
+            request: abstract_dex_standard::msg::DexExecuteMsg::AnsAction {
+                dex: WYNDEX.into(),
+                action: DexAnsAction::Swap {
+                    offer_asset: AnsAsset::new(EUR, 100u128),
+                    ask_asset: AssetEntry::new(USD),
+                    max_spread: None,
+                    belief_price: None,
+                    min_receive: None,
+                    bypass_slippage: false,
+                    output_recipient: None,
+                },
+            },
+        },
+    );
+
+    // exempt caller doesn't pay the swap fee
+    dex_adapter
+        .call_as(&protocol_contract)
+        .execute(&swap_msg, None)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_900);
+    let account0_eur_balance = chain.query_balance(&account0_proxy, EUR)?;
+    assert_that!(account0_eur_balance.u128()).is_equal_to(0);
+
+    // non-exempt caller (the manager) still pays the swap fee
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let account0_eur_balance = chain.query_balance(&account0_proxy, EUR)?;
+    assert_that!(account0_eur_balance.u128()).is_equal_to(1);
+
+    // removed callers pay the swap fee again
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFeeExemptCallers {
+            to_add: vec![],
+            to_remove: vec![protocol_contract.to_string()],
+        },
+    )?;
+    dex_adapter
+        .call_as(&protocol_contract)
+        .execute(&swap_msg, None)?;
+    let account0_eur_balance = chain.query_balance(&account0_proxy, EUR)?;
+    assert_that!(account0_eur_balance.u128()).is_equal_to(2);
+
+    Ok(())
+}
+
+#[test]
+fn unauthorized_update_fee_exempt_callers() -> anyhow::Result<()> {
+    let (_, _, _, account, _) = setup_mock()?;
+
+    let update_exempt_callers_msg = abstract_dex_standard::msg::ExecuteMsg::Module(
+        abstract_adapter::std::adapter::AdapterRequestMsg {
+            proxy_address: None,
+            request: abstract_dex_standard::msg::DexExecuteMsg::UpdateFeeExemptCallers {
+                to_add: vec![account.proxy.addr_str()?],
+                to_remove: vec![],
+            },
+        },
+    );
+
+    let err = account
+        .manager
+        .execute_on_module(DEX_ADAPTER_ID, update_exempt_callers_msg)
+        .unwrap_err();
+    assert_eq!(dex_err(err), DexError::Unauthorized {});
+    Ok(())
+}
+
+#[test]
+fn deprecated_dex_warning() -> anyhow::Result<()> {
+    let (_, _, dex_adapter, os, abstr) = setup_mock()?;
+
+    let swap_action = DexAnsAction::Swap {
+        offer_asset: AnsAsset::new(EUR, 100u128),
+        ask_asset: AssetEntry::new(USD),
+        max_spread: Some(Decimal::percent(30)),
+        belief_price: None,
+        min_receive: None,
+        bypass_slippage: false,
+        output_recipient: None,
     };
-    let dex_err: DexError = orch_error.downcast().unwrap();
-    assert_eq!(dex_err, DexError::Unauthorized {});
+
+    // current dex doesn't warn
+    let res = dex_adapter.ans_action(WYNDEX.into(), swap_action.clone(), &os)?;
+    assert!(res.event_attr_value("wasm", "deprecation_warning").is_err());
+
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateDeprecatedDexes {
+            to_add: vec![WYNDEX_WITHOUT_CHAIN.to_owned()],
+            to_remove: vec![],
+        },
+    )?;
+
+    // deprecated dex still swaps but warns
+    let res = dex_adapter.ans_action(WYNDEX.into(), swap_action, &os)?;
+    let warning = res.event_attr_value("wasm", "deprecation_warning")?;
+    assert_eq!(
+        warning,
+        format!("{WYNDEX_WITHOUT_CHAIN} is deprecated, consider migrating to another dex")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn min_pool_liquidity() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    let update_min_pool_liquidity = |to_add: Vec<(&str, u128)>, to_remove: Vec<&str>| {
+        admin_request(
+            &dex_adapter,
+            &abstr,
+            DexExecuteMsg::UpdateMinPoolLiquidity {
+                to_add: to_add
+                    .into_iter()
+                    .map(|(asset, min_liquidity)| (AssetEntry::new(asset), min_liquidity.into()))
+                    .collect(),
+                to_remove: to_remove.into_iter().map(AssetEntry::new).collect(),
+            },
+        )
+    };
+
+    // EUR/USD pool holds 10_000 of both assets, which is above the minimums
+    update_min_pool_liquidity(vec![(EUR, 5_000), (USD, 5_000)], vec![])?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_900);
+
+    // Pool is too shallow for the new minimum of the ask asset
+    update_min_pool_liquidity(vec![(USD, 20_000)], vec![])?;
+    let err = dex_adapter
+        .ans_swap((EUR, 100), USD, WYNDEX.into(), &os)
+        .unwrap_err();
+    assert!(matches!(
+        dex_err(err),
+        DexError::InsufficientPoolLiquidity { min_liquidity, .. } if min_liquidity.u128() == 20_000
+    ));
+
+    // Removing the minimum allows swapping on the pool again
+    update_min_pool_liquidity(vec![], vec![USD])?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_800);
+
+    Ok(())
+}
+
+#[test]
+fn asset_list_modes() -> anyhow::Result<()> {
+    let (_, _, dex_adapter, os, abstr) = setup_mock()?;
+
+    let execute_as_admin = |request: DexExecuteMsg| admin_request(&dex_adapter, &abstr, request);
+    let swap_err = |ask_asset: &str| {
+        let err = dex_adapter
+            .ans_swap((EUR, 100), ask_asset, WYNDEX.into(), &os)
+            .unwrap_err();
+        dex_err(err)
+    };
+
+    // Blocked assets can't be swapped in blocklist mode
+    execute_as_admin(DexExecuteMsg::UpdateBlockedAssets {
+        to_add: vec![AssetEntry::new(USD)],
+        to_remove: vec![],
+    })?;
+    execute_as_admin(DexExecuteMsg::UpdateAssetListMode {
+        mode: Some(AssetListMode::Blocklist),
+    })?;
+    assert!(matches!(swap_err(USD), DexError::AssetBlocked { .. }));
+    dex_adapter.ans_swap((EUR, 100), RAW_TOKEN, WYNDEX.into(), &os)?;
+
+    // Only allowed assets can be swapped in allowlist mode
+    execute_as_admin(DexExecuteMsg::UpdateAllowedAssets {
+        to_add: vec![AssetEntry::new(EUR), AssetEntry::new(USD)],
+        to_remove: vec![],
+    })?;
+    execute_as_admin(DexExecuteMsg::UpdateAssetListMode {
+        mode: Some(AssetListMode::Allowlist),
+    })?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    assert!(matches!(
+        swap_err(RAW_TOKEN),
+        DexError::AssetNotAllowed { .. }
+    ));
+
+    // Without a mode every asset can be swapped
+    execute_as_admin(DexExecuteMsg::UpdateAssetListMode { mode: None })?;
+    dex_adapter.ans_swap((EUR, 100), RAW_TOKEN, WYNDEX.into(), &os)?;
+
+    Ok(())
+}
+
+#[test]
+fn max_pool_share() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    let update_max_pool_share = |max_share: Option<Decimal>| {
+        admin_request(
+            &dex_adapter,
+            &abstr,
+            DexExecuteMsg::UpdateMaxPoolShare { max_share },
+        )
+    };
+
+    // Share has to be a fraction of the reserves
+    assert!(update_max_pool_share(Some(Decimal::percent(101))).is_err());
+    assert!(update_max_pool_share(Some(Decimal::zero())).is_err());
+
+    // EUR/USD pool holds 10_000 EUR, so 3_000 EUR can be offered at most
+    update_max_pool_share(Some(Decimal::percent(30)))?;
+    dex_adapter.ans_swap((EUR, 3_000), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(7_000);
+
+    // 5_000 EUR (4_950 after the fee) is more than 30% of the 12_970 EUR reserves
+    let err = dex_adapter
+        .ans_swap((EUR, 5_000), USD, WYNDEX.into(), &os)
+        .unwrap_err();
+    assert_eq!(
+        dex_err(err),
+        DexError::SwapTooLargeForPool {
+            offer_amount: 4_950u128.into(),
+            max_offer_amount: 3_891u128.into(),
+        }
+    );
+
+    // Without the limit the swap goes through
+    update_max_pool_share(None)?;
+    dex_adapter.ans_swap((EUR, 5_000), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(2_000);
+
+    Ok(())
+}
+
+#[test]
+fn volume_cap() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    let update_volume_caps = |to_add: Vec<(AssetEntry, VolumeCap)>, to_remove: Vec<AssetEntry>| {
+        admin_request(
+            &dex_adapter,
+            &abstr,
+            DexExecuteMsg::UpdateVolumeCaps { to_add, to_remove },
+        )
+    };
+    let eur_cap = |max_volume: u128, window: u64| {
+        vec![(
+            AssetEntry::new(EUR),
+            VolumeCap {
+                max_volume: max_volume.into(),
+                window,
+            },
+        )]
+    };
+
+    // 1_000 EUR can be swapped per minute
+    update_volume_caps(eur_cap(1_000, 60), vec![])?;
+    dex_adapter.ans_swap((EUR, 600), USD, WYNDEX.into(), &os)?;
+    dex_adapter.ans_swap((EUR, 400), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_000);
+
+    // The cap is reached for this window
+    chain.wait_seconds(59)?;
+    let err = dex_adapter
+        .ans_swap((EUR, 1), USD, WYNDEX.into(), &os)
+        .unwrap_err();
+    assert_eq!(
+        dex_err(err),
+        DexError::GlobalVolumeCapExceeded {
+            asset: EUR.to_owned(),
+            offer_amount: 1u128.into(),
+            volume: 1_000u128.into(),
+            max_volume: 1_000u128.into(),
+        }
+    );
+
+    // USD has no cap, so it can still be swapped
+    dex_adapter.ans_swap((USD, 500), EUR, WYNDEX.into(), &os)?;
+
+    // Once the window rolls over the volume starts from zero again
+    chain.wait_seconds(1)?;
+    dex_adapter.ans_swap((EUR, 1_000), USD, WYNDEX.into(), &os)?;
+
+    // Without the cap the swap goes through
+    update_volume_caps(vec![], vec![AssetEntry::new(EUR)])?;
+    dex_adapter.ans_swap((EUR, 1_000), USD, WYNDEX.into(), &os)?;
+
+    // A new cap doesn't count the volume swapped under the previous one
+    update_volume_caps(eur_cap(1_000, 60), vec![])?;
+    dex_adapter.ans_swap((EUR, 1_000), USD, WYNDEX.into(), &os)?;
+    update_volume_caps(eur_cap(2_000, 60), vec![])?;
+    dex_adapter.ans_swap((EUR, 2_000), USD, WYNDEX.into(), &os)?;
+
+    // Caps without volume or window are rejected
+    for volume_cap in [eur_cap(0, 60), eur_cap(1_000, 0)] {
+        let err = update_volume_caps(volume_cap, vec![]).unwrap_err();
+        assert_eq!(dex_err(err), DexError::InvalidVolumeCap {});
+    }
+
+    Ok(())
+}
+
+#[test]
+fn large_swap_delay() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    let request = |proxy: &Addr, request: DexExecuteMsg| {
+        dex_adapter.execute(
+            &abstract_dex_standard::msg::ExecuteMsg::Module(
+                abstract_adapter::std::adapter::AdapterRequestMsg {
+                    proxy_address: Some(proxy.to_string()),
+                    request,
+                },
+            ),
+            None,
+        )
+    };
+    let schedule_swap = |offer_amount: u128, min_receive: Option<u128>| {
+        request(
+            &proxy_addr,
+            DexExecuteMsg::ScheduleLargeSwap {
+                dex: WYNDEX.into(),
+                action: DexAnsAction::Swap {
+                    offer_asset: AnsAsset::new(EUR, offer_amount),
+                    ask_asset: AssetEntry::new(USD),
+                    max_spread: None,
+                    belief_price: None,
+                    min_receive: min_receive.map(Into::into),
+                    bypass_slippage: false,
+                    output_recipient: None,
+                },
+            },
+        )
+    };
+
+    let update_thresholds = |to_add: Vec<(AssetEntry, Uint128)>, to_remove: Vec<AssetEntry>| {
+        admin_request(
+            &dex_adapter,
+            &abstr,
+            DexExecuteMsg::UpdateLargeSwapThresholds { to_add, to_remove },
+        )
+    };
+
+    // Swaps over 1_000 EUR or 100 USD have to be scheduled 10 blocks ahead
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateLargeSwapDelay { delay: Some(10) },
+    )?;
+    update_thresholds(
+        vec![
+            (AssetEntry::new(EUR), 1_000u128.into()),
+            (AssetEntry::new(USD), 100u128.into()),
+        ],
+        vec![],
+    )?;
+    dex_adapter.ans_swap((EUR, 1_000), USD, WYNDEX.into(), &os)?;
+    let err = dex_adapter
+        .ans_swap((EUR, 2_000), USD, WYNDEX.into(), &os)
+        .unwrap_err();
+    assert_eq!(
+        dex_err(err),
+        DexError::SwapMustBeScheduled {
+            offer_amount: 2_000u128.into(),
+            threshold: 1_000u128.into(),
+        }
+    );
+
+    // Each asset has its own threshold
+    let err = dex_adapter
+        .ans_swap((USD, 500), EUR, WYNDEX.into(), &os)
+        .unwrap_err();
+    assert_eq!(
+        dex_err(err),
+        DexError::SwapMustBeScheduled {
+            offer_amount: 500u128.into(),
+            threshold: 100u128.into(),
+        }
+    );
+
+    // Messages of large swaps can't be generated either
+    let msg = DexQueryMsg::GenerateMessages {
+        message: DexExecuteMsg::AnsAction {
+            dex: WYNDEX.into(),
+            action: DexAnsAction::Swap {
+                offer_asset: AnsAsset::new(EUR, 2_000u128),
+                ask_asset: AssetEntry::new(USD),
+                max_spread: None,
+                belief_price: None,
+                min_receive: None,
+                bypass_slippage: false,
+                output_recipient: None,
+            },
+        },
+        addr_as_sender: proxy_addr.to_string(),
+    };
+    let res: Result<GenerateMessagesResponse, _> = dex_adapter.query(&msg.into());
+    assert_that!(res.unwrap_err().to_string()).contains("has to be scheduled");
+
+    // The scheduled swap can't be executed before the delay elapsed
+    let execute_after = chain.block_info()?.height + 10;
+    let res = schedule_swap(2_000, None)?;
+    assert_that!(res.event_attr_value("wasm", "swap_id")?).is_equal_to("0".to_owned());
+    chain.wait_blocks(9)?;
+    let err = request(
+        &proxy_addr,
+        DexExecuteMsg::ExecuteScheduledSwap { swap_id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(
+        dex_err(err),
+        DexError::ScheduledSwapNotReady {
+            swap_id: 0,
+            execute_after,
+        }
+    );
+
+    // Once it elapsed the swap is executed once
+    chain.wait_blocks(1)?;
+    request(
+        &proxy_addr,
+        DexExecuteMsg::ExecuteScheduledSwap { swap_id: 0 },
+    )?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(7_000);
+    let err = request(
+        &proxy_addr,
+        DexExecuteMsg::ExecuteScheduledSwap { swap_id: 0 },
+    )
+    .unwrap_err();
+    assert_eq!(dex_err(err), DexError::ScheduledSwapNotFound { swap_id: 0 });
+
+    // The slippage is checked at execution and other accounts can't execute the swap
+    schedule_swap(2_000, Some(2_000))?;
+    chain.wait_blocks(10)?;
+    let err = admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::ExecuteScheduledSwap { swap_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(dex_err(err), DexError::ScheduledSwapNotFound { swap_id: 1 });
+    let res = request(
+        &proxy_addr,
+        DexExecuteMsg::ExecuteScheduledSwap { swap_id: 1 },
+    );
+    assert_that!(res).is_err();
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(7_000);
+
+    // A cancelled swap can't be executed anymore
+    schedule_swap(2_000, None)?;
+    request(
+        &proxy_addr,
+        DexExecuteMsg::CancelScheduledSwap { swap_id: 2 },
+    )?;
+    chain.wait_blocks(10)?;
+    let err = request(
+        &proxy_addr,
+        DexExecuteMsg::ExecuteScheduledSwap { swap_id: 2 },
+    )
+    .unwrap_err();
+    assert_eq!(dex_err(err), DexError::ScheduledSwapNotFound { swap_id: 2 });
+    let err = request(
+        &proxy_addr,
+        DexExecuteMsg::CancelScheduledSwap { swap_id: 2 },
+    )
+    .unwrap_err();
+    assert_eq!(dex_err(err), DexError::ScheduledSwapNotFound { swap_id: 2 });
+
+    // Without a threshold the swaps of an asset aren't delayed
+    update_thresholds(vec![], vec![AssetEntry::new(EUR)])?;
+    dex_adapter.ans_swap((EUR, 2_000), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(5_000);
+
+    Ok(())
+}
+
+#[test]
+fn swap_cooldown() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    // Accounts can swap once a minute
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateSwapCooldown { cooldown: Some(60) },
+    )?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let available_at = chain.block_info()?.time.seconds() + 60;
+
+    // Within the cooldown
+    chain.wait_seconds(59)?;
+    let err = dex_adapter
+        .ans_swap((EUR, 100), USD, WYNDEX.into(), &os)
+        .unwrap_err();
+    assert_eq!(dex_err(err), DexError::SwapCooldownActive { available_at });
+
+    // After the cooldown
+    chain.wait_seconds(1)?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_800);
+
+    // Keepers aren't subject to the cooldown
+    let keeper = chain.addr_make("keeper");
+    os.manager.update_adapter_authorized_addresses(
+        DEX_ADAPTER_ID,
+        vec![keeper.to_string()],
+        vec![],
+    )?;
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateKeepers {
+            to_add: vec![keeper.to_string()],
+            to_remove: vec![],
+        },
+    )?;
+    let swap_msg = abstract_dex_standard::msg::ExecuteMsg::Module(
+        abstract_adapter::std::adapter::AdapterRequestMsg {
+            proxy_address: Some(proxy_addr.to_string()),
+            request: DexExecuteMsg::AnsAction {
+                dex: WYNDEX.into(),
+                action: DexAnsAction::Swap {
+                    offer_asset: AnsAsset::new(EUR, 100u128),
+                    ask_asset: AssetEntry::new(USD),
+                    max_spread: None,
+                    belief_price: None,
+                    min_receive: None,
+                    bypass_slippage: false,
+                    output_recipient: None,
+                },
+            },
+        },
+    );
+    dex_adapter.call_as(&keeper).execute(&swap_msg, None)?;
+    dex_adapter.call_as(&keeper).execute(&swap_msg, None)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_600);
+
+    // Without the cooldown swaps aren't rate-limited
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateSwapCooldown { cooldown: None },
+    )?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_400);
+
+    Ok(())
+}
+
+#[test]
+fn keeper_bypass_slippage() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    let keeper = chain.addr_make("keeper");
+    let operator = chain.addr_make("operator");
+    os.manager.update_adapter_authorized_addresses(
+        DEX_ADAPTER_ID,
+        vec![keeper.to_string(), operator.to_string()],
+        vec![],
+    )?;
+
+    // 1_000 USD can't be received for 100 EUR
+    let swap_msg = |bypass_slippage: bool| {
+        abstract_dex_standard::msg::ExecuteMsg::Module(
+            abstract_adapter::std::adapter::AdapterRequestMsg {
+                proxy_address: Some(proxy_addr.to_string()),
+                request: DexExecuteMsg::AnsAction {
+                    dex: WYNDEX.into(),
+                    action: DexAnsAction::Swap {
+                        offer_asset: AnsAsset::new(EUR, 100u128),
+                        ask_asset: AssetEntry::new(USD),
+                        max_spread: None,
+                        belief_price: None,
+                        min_receive: Some(1_000u128.into()),
+                        bypass_slippage,
+                        output_recipient: None,
+                    },
+                },
+            },
+        )
+    };
+    // the swap is rejected by the pool's slippage check, not by authorization
+    let assert_slippage_err = |res: Result<_, CwOrchError>| {
+        let err = format!("{:?}", res.unwrap_err());
+        assert!(err.contains("max spread limit"), "{err}");
+    };
+
+    // bypass is ignored for callers that aren't keepers
+    assert_slippage_err(dex_adapter.call_as(&keeper).execute(&swap_msg(true), None));
+
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateKeepers {
+            to_add: vec![keeper.to_string()],
+            to_remove: vec![],
+        },
+    )?;
+
+    // keepers are protected unless they ask for the bypass
+    assert_slippage_err(dex_adapter.call_as(&keeper).execute(&swap_msg(false), None));
+
+    // authorized non-keepers still can't bypass
+    assert_slippage_err(
+        dex_adapter
+            .call_as(&operator)
+            .execute(&swap_msg(true), None),
+    );
+
+    // keeper bypasses the slippage protection
+    dex_adapter
+        .call_as(&keeper)
+        .execute(&swap_msg(true), None)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_900);
+
+    Ok(())
+}
+
+#[test]
+fn quote_cache() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    let keeper = chain.addr_make("keeper");
+    os.manager.update_adapter_authorized_addresses(
+        DEX_ADAPTER_ID,
+        vec![keeper.to_string()],
+        vec![],
+    )?;
+
+    let refresh_quote_msg = |dex: &str| {
+        abstract_dex_standard::msg::ExecuteMsg::Module(
+            abstract_adapter::std::adapter::AdapterRequestMsg {
+                proxy_address: Some(proxy_addr.to_string()),
+                request: DexExecuteMsg::RefreshQuote {
+                    offer_asset: AnsAsset::new(EUR, 1_000u128),
+                    ask_asset: AssetEntry::new(USD),
+                    dex: dex.into(),
+                },
+            },
+        )
+    };
+    let query_quote = || -> anyhow::Result<SimulateSwapResponse> {
+        let msg = DexQueryMsg::SimulateSwap {
+            offer_asset: AnsAsset::new(EUR, 1_000u128),
+            ask_asset: AssetEntry::new(USD),
+            dex: WYNDEX_WITHOUT_CHAIN.into(),
+        };
+        Ok(dex_adapter.query(&msg.into())?)
+    };
+
+    // only keepers can refresh quotes
+    let err = dex_adapter
+        .call_as(&keeper)
+        .execute(&refresh_quote_msg(WYNDEX_WITHOUT_CHAIN), None)
+        .unwrap_err();
+    assert_eq!(dex_err(err), DexError::NotKeeper {});
+
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateKeepers {
+            to_add: vec![keeper.to_string()],
+            to_remove: vec![],
+        },
+    )?;
+
+    // the cache has to be enabled
+    let err = dex_adapter
+        .call_as(&keeper)
+        .execute(&refresh_quote_msg(WYNDEX_WITHOUT_CHAIN), None)
+        .unwrap_err();
+    assert_eq!(dex_err(err), DexError::QuoteCacheDisabled {});
+
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateQuoteCacheTtl { ttl: Some(60) },
+    )?;
+    dex_adapter
+        .call_as(&keeper)
+        .execute(&refresh_quote_msg(WYNDEX_WITHOUT_CHAIN), None)?;
+    let cached_quote = query_quote()?;
+    assert_that!(cached_quote.usage_fee.u128()).is_equal_to(10);
+
+    // within the TTL the cached quote is served, even though the fee changed
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateFee {
+            swap_fee: Some(Decimal::percent(5)),
+            recipient_account: None,
+        },
+    )?;
+    chain.wait_seconds(59)?;
+    assert_that!(query_quote()?).is_equal_to(&cached_quote);
+
+    // after the TTL the quote is recomputed
+    chain.wait_seconds(1)?;
+    let quote = query_quote()?;
+    assert_that!(quote.usage_fee.u128()).is_equal_to(50);
+
+    // swapping on the pair drops its cached quotes, also when cached under the chain-prefixed name
+    dex_adapter
+        .call_as(&keeper)
+        .execute(&refresh_quote_msg(WYNDEX), None)?;
+    assert_that!(query_quote()?).is_equal_to(&quote);
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let post_swap_quote = query_quote()?;
+    assert_that!(post_swap_quote.return_amount).is_less_than(quote.return_amount);
+
+    Ok(())
+}
+
+#[test]
+fn price_feed() -> anyhow::Result<()> {
+    let (chain, _, dex_adapter, os, abstr) = setup_mock()?;
+
+    let update_price_feed = |enabled: bool| {
+        admin_request(
+            &dex_adapter,
+            &abstr,
+            DexExecuteMsg::UpdatePriceFeed { enabled },
+        )
+    };
+    let query_price_feed_on = |dex: &str| -> anyhow::Result<PriceFeedResponse> {
+        let msg = DexQueryMsg::PriceFeed {
+            offer_asset: AssetEntry::new(EUR),
+            ask_asset: AssetEntry::new(USD),
+            dex: dex.into(),
+        };
+        Ok(dex_adapter.query(&msg.into())?)
+    };
+    let query_price_feed = || query_price_feed_on(WYNDEX_WITHOUT_CHAIN);
+
+    // 99 EUR are offered after the fee, for which 98 USD are received
+    update_price_feed(true)?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let recorded = query_price_feed()?.price.unwrap();
+    assert_that!(recorded.price).is_equal_to(Decimal::from_ratio(98u128, 99u128));
+    assert_that!(recorded.recorded_at).is_equal_to(chain.block_info()?.time);
+
+    // The price is found under the dex name with the chain as well
+    assert_that!(query_price_feed_on(WYNDEX)?.price).is_equal_to(Some(recorded.clone()));
+
+    // The next swap overwrites the recorded price
+    chain.wait_seconds(10)?;
+    dex_adapter.ans_swap((EUR, 1_000), USD, WYNDEX.into(), &os)?;
+    let next_recorded = query_price_feed()?.price.unwrap();
+    assert_that!(next_recorded.price).is_less_than(recorded.price);
+    assert_that!(next_recorded.recorded_at).is_equal_to(chain.block_info()?.time);
+
+    // The opposite direction is recorded separately
+    let reverse_msg = DexQueryMsg::PriceFeed {
+        offer_asset: AssetEntry::new(USD),
+        ask_asset: AssetEntry::new(EUR),
+        dex: WYNDEX_WITHOUT_CHAIN.into(),
+    };
+    let reverse: PriceFeedResponse = dex_adapter.query(&reverse_msg.into())?;
+    assert_that!(reverse.price).is_none();
+
+    // Disabling the feed drops the recorded prices and stops recording them
+    update_price_feed(false)?;
+    assert_that!(query_price_feed()?.price).is_none();
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    assert_that!(query_price_feed()?.price).is_none();
+
+    Ok(())
+}
+
+#[test]
+fn secondary_ans_host() -> anyhow::Result<()> {
+    let (chain, wyndex, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    // The secondary ANS host knows the EUR/USD pool under other asset names
+    let secondary = abstract_interface::AnsHost::new("secondary_ans_host", chain.clone());
+    secondary.upload()?;
+    secondary.instantiate(
+        &abstract_adapter::std::ans_host::InstantiateMsg {
+            admin: chain.sender().to_string(),
+        },
+        None,
+        None,
+    )?;
+    secondary.update_asset_addresses(
+        vec![
+            ("euro".to_owned(), wyndex.eur_token.clone().into()),
+            ("dollar".to_owned(), wyndex.usd_token.clone().into()),
+        ],
+        vec![],
+    )?;
+    secondary.update_dexes(vec![WYNDEX_WITHOUT_CHAIN.to_owned()], vec![])?;
+    secondary.update_pools(
+        vec![(
+            PoolAddressBase::contract(wyndex.eur_usd_pair.to_string()),
+            PoolMetadata::constant_product(
+                WYNDEX_WITHOUT_CHAIN,
+                vec![AssetEntry::new("euro"), AssetEntry::new("dollar")],
+            ),
+        )],
+        vec![],
+    )?;
+
+    let update_secondary_ans_host = |ans_host: Option<String>| {
+        admin_request(
+            &dex_adapter,
+            &abstr,
+            DexExecuteMsg::UpdateSecondaryAnsHost { ans_host },
+        )
+    };
+
+    // The primary ANS host can't resolve the assets
+    let err = dex_adapter
+        .ans_swap(("euro", 100), "dollar", WYNDEX.into(), &os)
+        .unwrap_err();
+    assert!(matches!(dex_err(err), DexError::AbstractSdk(_)));
+
+    // so the swap is resolved on the secondary ANS host
+    update_secondary_ans_host(Some(secondary.addr_str()?))?;
+    dex_adapter.ans_swap(("euro", 100), "dollar", WYNDEX.into(), &os)?;
+    let usd_balance = chain.query_balance(&proxy_addr, USD)?;
+    assert_that!(usd_balance.u128()).is_equal_to(98);
+
+    // Assets known by the primary ANS host are still resolved on it
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_800);
+
+    // Both ANS hosts failing is reported for each of them
+    let err = dex_adapter
+        .ans_swap(("yen", 100), "dollar", WYNDEX.into(), &os)
+        .unwrap_err();
+    assert!(matches!(dex_err(err), DexError::AnsFallbackFailed { .. }));
+
+    // Without the secondary ANS host the fallback is gone
+    update_secondary_ans_host(None)?;
+    let err = dex_adapter
+        .ans_swap(("euro", 100), "dollar", WYNDEX.into(), &os)
+        .unwrap_err();
+    assert!(matches!(dex_err(err), DexError::AbstractSdk(_)));
+
+    Ok(())
+}
+
+#[test]
+fn swap_through_router() -> anyhow::Result<()> {
+    let (chain, wyndex, dex_adapter, os, abstr) = setup_mock()?;
+    let proxy_addr = os.proxy.address()?;
+
+    let update_router = |router: Option<String>| {
+        admin_request(
+            &dex_adapter,
+            &abstr,
+            DexExecuteMsg::UpdateRouter {
+                dex: WYNDEX_WITHOUT_CHAIN.into(),
+                router,
+            },
+        )
+    };
+    let generate_swap_messages = || -> anyhow::Result<Vec<CosmosMsg>> {
+        let msg = DexQueryMsg::GenerateMessages {
+            message: DexExecuteMsg::AnsAction {
+                dex: WYNDEX.into(),
+                action: DexAnsAction::Swap {
+                    offer_asset: AnsAsset::new(EUR, 100u128),
+                    ask_asset: AssetEntry::new(USD),
+                    max_spread: None,
+                    belief_price: None,
+                    min_receive: None,
+                    bypass_slippage: false,
+                    output_recipient: None,
+                },
+            },
+            addr_as_sender: proxy_addr.to_string(),
+        };
+        let response: GenerateMessagesResponse = dex_adapter.query(&msg.into())?;
+        Ok(response.messages)
+    };
+    let swap_contract = |messages: &[CosmosMsg]| match &messages[0] {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => contract_addr.clone(),
+        _ => panic!("expected a swap message"),
+    };
+
+    // Swaps are executed on the pool by default
+    let direct_messages = generate_swap_messages()?;
+    assert_that!(swap_contract(&direct_messages)).is_equal_to(wyndex.eur_usd_pair.to_string());
+
+    // With a router, the same swap is executed through the router and charged the same fee
+    update_router(Some(wyndex.suite.multi_hop.to_string()))?;
+    let router_messages = generate_swap_messages()?;
+    assert_that!(swap_contract(&router_messages)).is_equal_to(wyndex.suite.multi_hop.to_string());
+    assert_that!(router_messages.len()).is_equal_to(direct_messages.len());
+    assert_that!(router_messages[1]).is_equal_to(&direct_messages[1]);
+
+    // and has the same outcome, as the checks of the given pool don't apply to the router's pools
+    admin_request(
+        &dex_adapter,
+        &abstr,
+        DexExecuteMsg::UpdateMinPoolLiquidity {
+            to_add: vec![(AssetEntry::new(USD), 20_000u128.into())],
+            to_remove: vec![],
+        },
+    )?;
+    dex_adapter.ans_swap((EUR, 100), USD, WYNDEX.into(), &os)?;
+    let eur_balance = chain.query_balance(&proxy_addr, EUR)?;
+    assert_that!(eur_balance.u128()).is_equal_to(9_900);
+    let usd_balance = chain.query_balance(&proxy_addr, USD)?;
+    assert_that!(usd_balance.u128()).is_equal_to(98);
+
+    // Removing the router executes swaps on the pool again
+    update_router(None)?;
+    assert_that!(generate_swap_messages()?).is_equal_to(direct_messages);
+
     Ok(())
 }